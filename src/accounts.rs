@@ -1,10 +1,225 @@
+use chrono::{DateTime, Utc};
+use futures_util::stream::{self, StreamExt};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 use crate::client::Client;
 use crate::errors::Result;
 use crate::item::Item;
+use crate::types::Money;
 
-#[derive(Deserialize, Debug, Clone)]
+/// The maximum number of /accounts/get requests `get_accounts_for_items` keeps in flight.
+const ACCOUNTS_FOR_ITEMS_CONCURRENCY: usize = 8;
+
+string_enum! {
+    /// The type of an account.
+    pub enum AccountType {
+        /// An investment account.
+        Investment => "investment",
+        /// A credit card type account.
+        Credit => "credit",
+        /// A depository account, such as checking or savings.
+        Depository => "depository",
+        /// A loan type account.
+        Loan => "loan",
+        /// An investment account. Used in place of `investment` by some older Items.
+        Brokerage => "brokerage",
+        /// A non-specified account type.
+        Other => "other",
+        _ => Unknown,
+    }
+}
+
+string_enum! {
+    /// The subtype of an account, which further classifies its [`AccountType`].
+    pub enum AccountSubtype {
+        /// A 401(a) plan.
+        Plan401a => "401a",
+        /// A 401(k) plan.
+        Plan401k => "401k",
+        /// A 403(b) plan.
+        Plan403b => "403B",
+        /// A 457(b) plan.
+        Plan457b => "457b",
+        /// A 529 education savings plan.
+        Plan529 => "529",
+        /// A standard brokerage account.
+        Brokerage => "brokerage",
+        /// A cash individual savings account (UK).
+        CashIsa => "cash isa",
+        /// A Coverdell education savings account.
+        EducationSavingsAccount => "education savings account",
+        /// A guaranteed investment certificate (Canada).
+        Gic => "gic",
+        /// A health reimbursement arrangement.
+        HealthReimbursementArrangement => "health reimbursement arrangement",
+        /// A health savings account.
+        Hsa => "hsa",
+        /// An individual savings account (UK).
+        Isa => "isa",
+        /// A traditional individual retirement account.
+        Ira => "ira",
+        /// A life income fund (Canada).
+        Lif => "lif",
+        /// A locked-in retirement account (Canada).
+        Lira => "lira",
+        /// A locked-in retirement income fund (Canada).
+        Lrif => "lrif",
+        /// A locked-in retirement savings plan (Canada).
+        Lrsp => "lrsp",
+        /// A non-taxable brokerage account.
+        NonTaxableBrokerageAccount => "non-taxable brokerage account",
+        /// An account whose subtype does not fit another category.
+        Other => "other",
+        /// A prescribed registered retirement income fund (Canada).
+        Prif => "prif",
+        /// A registered disability savings plan (Canada).
+        Rdsp => "rdsp",
+        /// A registered education savings plan (Canada).
+        Resp => "resp",
+        /// A restricted life income fund (Canada).
+        Rlif => "rlif",
+        /// A registered retirement income fund (Canada).
+        Rrif => "rrif",
+        /// A pension account.
+        Pension => "pension",
+        /// A profit sharing plan.
+        ProfitSharingPlan => "profit sharing plan",
+        /// A retirement account.
+        Retirement => "retirement",
+        /// A Roth IRA.
+        Roth => "roth",
+        /// A Roth 401(k) plan.
+        Roth401k => "roth 401k",
+        /// A registered retirement savings plan (Canada).
+        Rrsp => "rrsp",
+        /// A simplified employee pension IRA.
+        SepIra => "sep ira",
+        /// A savings incentive match plan for employees IRA.
+        SimpleIra => "simple ira",
+        /// A self-invested personal pension (UK).
+        Sipp => "sipp",
+        /// A standard stock plan.
+        StockPlan => "stock plan",
+        /// A thrift savings plan.
+        ThriftSavingsPlan => "thrift savings plan",
+        /// A tax-free savings account (Canada).
+        Tfsa => "tfsa",
+        /// A trust account.
+        Trust => "trust",
+        /// A Uniform Gifts to Minors Act account.
+        Ugma => "ugma",
+        /// A Uniform Transfers to Minors Act account.
+        Utma => "utma",
+        /// A tax-deferred variable annuity account.
+        VariableAnnuity => "variable annuity",
+        /// A bank-issued credit card.
+        CreditCard => "credit card",
+        /// A PayPal account.
+        Paypal => "paypal",
+        /// A certificate of deposit account.
+        Cd => "cd",
+        /// A checking account.
+        Checking => "checking",
+        /// A savings account.
+        Savings => "savings",
+        /// A money market account.
+        MoneyMarket => "money market",
+        /// A prepaid debit card.
+        Prepaid => "prepaid",
+        /// An auto loan.
+        Auto => "auto",
+        /// A commercial loan.
+        Commercial => "commercial",
+        /// A construction loan.
+        Construction => "construction",
+        /// A consumer loan.
+        Consumer => "consumer",
+        /// A home loan.
+        Home => "home",
+        /// A home equity line of credit.
+        HomeEquity => "home equity",
+        /// A general loan.
+        Loan => "loan",
+        /// A mortgage loan.
+        Mortgage => "mortgage",
+        /// An overdraft line of credit.
+        Overdraft => "overdraft",
+        /// A pre-approved line of credit.
+        LineOfCredit => "line of credit",
+        /// A student loan.
+        Student => "student",
+        /// A cash management account.
+        CashManagement => "cash management",
+        /// A Keogh self-employed pension plan.
+        Keogh => "keogh",
+        /// A mutual fund account.
+        MutualFund => "mutual fund",
+        /// A recurring deposit account.
+        Recurring => "recurring",
+        /// A rewards account.
+        Rewards => "rewards",
+        /// A safe deposit account.
+        SafeDeposit => "safe deposit",
+        /// A salary reduction simplified employee pension plan.
+        Sarsep => "sarsep",
+        _ => Unknown,
+    }
+}
+
+string_enum! {
+    /// The verification status of an account added through Automated or Manual micro-deposits.
+    pub enum VerificationStatus {
+        /// The Item is pending automatic verification.
+        PendingAutomaticVerification => "pending_automatic_verification",
+        /// The Item is pending manual micro-deposit verification. Items remain in this state until the user successfully verifies the two amounts.
+        PendingManualVerification => "pending_manual_verification",
+        /// The Item has successfully been automatically verified.
+        AutomaticallyVerified => "automatically_verified",
+        /// The Item has successfully been manually verified.
+        ManuallyVerified => "manually_verified",
+        /// Plaid was unable to automatically verify the deposit within 7 calendar days and will no longer attempt to validate the Item.
+        VerificationExpired => "verification_expired",
+        /// The Item failed manual micro-deposit verification because the user exhausted all 3 verification attempts.
+        VerificationFailed => "verification_failed",
+        /// The Item has successfully been verified using Plaid's data sources.
+        DatabaseMatched => "database_matched",
+        _ => Other,
+    }
+}
+
+impl VerificationStatus {
+    /// Returns true if verification completed successfully and the account numbers can be used.
+    pub fn is_verified(&self) -> bool {
+        matches!(
+            self,
+            Self::AutomaticallyVerified | Self::ManuallyVerified | Self::DatabaseMatched
+        )
+    }
+
+    /// Returns true if verification is still in progress.
+    pub fn is_pending(&self) -> bool {
+        matches!(
+            self,
+            Self::PendingAutomaticVerification | Self::PendingManualVerification
+        )
+    }
+}
+
+string_enum! {
+    /// Whether an account is held by a business or an individual.
+    pub enum HolderCategory {
+        /// A business account.
+        Business => "business",
+        /// A personal account.
+        Personal => "personal",
+        /// The institution did not indicate whether the account is business or personal.
+        Unrecognized => "unrecognized",
+        _ => Other,
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Account {
     /// Plaid’s unique identifier for the account.
     pub account_id: String,
@@ -21,19 +236,63 @@ pub struct Account {
     /// Possible values: 401a, 401k, 403B, 457b, 529, brokerage, cash isa, education savings account, gic, health reimbursement arrangement, hsa, isa, ira, lif, lira, lrif, lrsp, non-taxable brokerage account, other, prif, rdsp, resp, rlif, rrif, pension, profit sharing plan, retirement, roth, roth 401k, rrsp, sep ira, simple ira, sipp, stock plan, thrift savings plan, tfsa, trust, ugma, utma, variable annuity, credit card, paypal, cd, checking, savings, money market, prepaid, auto, commercial, construction, consumer, home, home equity, loan, mortgage, overdraft, line of credit, student, cash management, keogh, mutual fund, recurring, rewards, safe deposit, sarsep
     pub subtype: Option<String>,
     /// The current verification status of an Auth Item initiated through Automated or Manual micro-deposits.  Returned for Auth Items only.
-    pub verification_status: Option<String>,
+    pub verification_status: Option<VerificationStatus>,
+    /// Indicates the account's categorization as either a personal or a business account. Only returned by some institutions.
+    pub holder_category: Option<HolderCategory>,
+}
+
+/// An account returned by one of the account, identity or asset report endpoints, whose type and subtype are kept as raw strings.
+pub trait HasAccountType {
+    /// The account type as returned by Plaid, e.g. "depository".
+    fn raw_type(&self) -> &str;
+
+    /// The account subtype as returned by Plaid, e.g. "checking".
+    fn raw_subtype(&self) -> Option<&str>;
+
+    /// Returns the account type as an [`AccountType`].
+    fn account_type(&self) -> AccountType {
+        AccountType::from(self.raw_type())
+    }
+
+    /// Returns the account subtype as an [`AccountSubtype`], if there is one.
+    fn account_subtype(&self) -> Option<AccountSubtype> {
+        self.raw_subtype().map(AccountSubtype::from)
+    }
 }
 
-#[derive(Deserialize, Debug, Clone)]
+macro_rules! impl_has_account_type {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl HasAccountType for $ty {
+                fn raw_type(&self) -> &str {
+                    &self.r#type
+                }
+
+                fn raw_subtype(&self) -> Option<&str> {
+                    self.subtype.as_deref()
+                }
+            }
+        )*
+    };
+}
+
+impl_has_account_type!(
+    Account,
+    crate::asset_report::AssetReportAccount,
+    crate::identity::AccountWithMatchScores,
+    crate::identity::AccountWithOwners,
+);
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct AccountBalances {
     /// The amount of funds available to be withdrawn from the account, as determined by the financial institution.
-    pub available: Option<f64>,
+    pub available: Option<Money>,
     /// The total amount of funds in or owed by the account.
-    pub current: f64,
+    pub current: Money,
     /// For credit-type accounts, this represents the credit limit.
     /// For depository-type accounts, this represents the pre-arranged overdraft limit, which is common for current (checking) accounts in Europe.
     /// In North America, this field is typically only available for credit-type accounts.
-    pub limit: Option<f64>,
+    pub limit: Option<Money>,
     /// The ISO-4217 currency code of the balance. Always null if unofficial_currency_code is non-null.
     pub iso_currency_code: Option<String>,
     /// The unofficial currency code associated with the balance. Always null if iso_currency_code is non-null.
@@ -49,13 +308,17 @@ struct GetBalancesRequest<'a> {
     options: Option<GetBalancesOptions<'a>>,
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Debug, Clone, Default)]
 pub struct GetBalancesOptions<'a> {
     /// A list of account_ids to retrieve for the Item.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub account_ids: Option<&'a [&'a str]>,
+    /// Timestamp in ISO 8601 format (YYYY-MM-DDTHH:mm:ssZ) indicating the oldest acceptable balance when making a request to /accounts/balance/get. If the balance that is pulled for ins_128026 (Capital One) is older than the given timestamp, an INVALID_REQUEST error with the code of LAST_UPDATED_DATETIME_OUT_OF_RANGE will be returned with the most recent timestamp for the requested account contained in the response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_last_updated_datetime: Option<DateTime<Utc>>,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct GetBalancesResponse {
     /// A unique identifier for the request, which can be used for troubleshooting. This identifier, like all Plaid identifiers, is case sensitive.
     pub request_id: String,
@@ -63,6 +326,39 @@ pub struct GetBalancesResponse {
     pub accounts: Vec<Account>,
 }
 
+impl GetBalancesResponse {
+    /// Sums the current balance of every account, keyed by its ISO-4217 currency code, or its unofficial currency code if it has none. Accounts without a currency code are skipped.
+    ///
+    /// * `liabilities_negative` - If true, the balances of credit and loan accounts, which are amounts owed, are subtracted rather than added.
+    pub fn total_by_currency(&self, liabilities_negative: bool) -> HashMap<String, Money> {
+        let mut totals = HashMap::new();
+        for account in &self.accounts {
+            let balances = &account.balances;
+            let currency = match balances
+                .iso_currency_code
+                .as_ref()
+                .or(balances.unofficial_currency_code.as_ref())
+            {
+                Some(currency) => currency,
+                None => continue,
+            };
+            let is_liability = matches!(
+                account.account_type(),
+                AccountType::Credit | AccountType::Loan
+            );
+            let amount = if liabilities_negative && is_liability {
+                -balances.current
+            } else {
+                balances.current
+            };
+            *totals
+                .entry(currency.clone())
+                .or_insert_with(Money::default) += amount;
+        }
+        totals
+    }
+}
+
 #[derive(Serialize)]
 struct GetAccountsRequest<'a> {
     client_id: &'a str,
@@ -78,7 +374,7 @@ pub struct GetAccountsOptions<'a> {
     pub account_ids: Option<&'a [&'a str]>,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct GetAccountsResponse {
     /// A unique identifier for the request, which can be used for troubleshooting. This identifier, like all Plaid identifiers, is case sensitive.
     pub request_id: String,
@@ -88,6 +384,24 @@ pub struct GetAccountsResponse {
     pub item: Item,
 }
 
+impl GetAccountsResponse {
+    /// Returns the accounts of the given type.
+    pub fn filter_by_type(&self, account_type: AccountType) -> Vec<&Account> {
+        self.accounts
+            .iter()
+            .filter(|account| account.account_type() == account_type)
+            .collect()
+    }
+
+    /// Returns the accounts of the given subtype.
+    pub fn filter_by_subtype(&self, account_subtype: AccountSubtype) -> Vec<&Account> {
+        self.accounts
+            .iter()
+            .filter(|account| account.account_subtype().as_ref() == Some(&account_subtype))
+            .collect()
+    }
+}
+
 impl Client {
     /// Retrieve real-time balance data.
     ///
@@ -103,8 +417,8 @@ impl Client {
         self.send_request(
             "accounts/balance/get",
             &GetBalancesRequest {
-                client_id: &self.client_id,
-                secret: &self.secret,
+                client_id: self.client_id(),
+                secret: self.secret(),
                 access_token,
                 options,
             },
@@ -126,26 +440,93 @@ impl Client {
         self.send_request(
             "accounts/get",
             &GetAccountsRequest {
-                client_id: &self.client_id,
-                secret: &self.secret,
+                client_id: self.client_id(),
+                secret: self.secret(),
                 access_token,
                 options,
             },
         )
         .await
     }
+
+    /// Retrieve accounts for several Items.
+    ///
+    /// Calls /accounts/get for every access token, with at most 8 requests in flight at once. Each result is returned next to its access token, in the same order as `access_tokens`, so that one failing Item does not hide the accounts of the others.
+    ///
+    /// * `access_tokens` - The access tokens of the Items to retrieve accounts for.
+    /// * `options` - An optional object to filter every /accounts/get request.
+    pub async fn get_accounts_for_items<'a>(
+        &self,
+        access_tokens: &[&str],
+        options: Option<GetAccountsOptions<'a>>,
+    ) -> Vec<(String, Result<GetAccountsResponse>)> {
+        stream::iter(access_tokens)
+            .map(|access_token| {
+                let options = options.clone();
+                async move {
+                    (
+                        access_token.to_string(),
+                        self.get_accounts(access_token, options).await,
+                    )
+                }
+            })
+            .buffered(ACCOUNTS_FOR_ITEMS_CONCURRENCY)
+            .collect()
+            .await
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::client::tests::{get_test_client, SANDBOX_INSTITUTION, TEST_PRODUCTS};
+    use crate::client::tests::{
+        get_mock_client, get_test_client, SANDBOX_INSTITUTION, TEST_PRODUCTS,
+    };
+    use crate::fixtures::{account_fixture, merge};
+
+    #[test]
+    fn test_account_type_and_subtype() {
+        let account: Account = serde_json::from_value(serde_json::json!({
+            "account_id": "blgvvBlXw3cq5GMPwqB6s6q4dLKB9WcVqGDGo",
+            "balances": {"available": 100, "current": 110, "limit": null, "iso_currency_code": "USD", "unofficial_currency_code": null},
+            "mask": "0000",
+            "name": "Plaid Checking",
+            "official_name": "Plaid Gold Standard 0% Interest Checking",
+            "type": "depository",
+            "subtype": "checking",
+            "verification_status": "pending_manual_verification",
+            "holder_category": "business"
+        }))
+        .unwrap();
+        assert_eq!(account.account_type(), AccountType::Depository);
+        assert_eq!(account.account_subtype(), Some(AccountSubtype::Checking));
+        assert_eq!(
+            account.verification_status,
+            Some(VerificationStatus::PendingManualVerification)
+        );
+        assert_eq!(account.holder_category, Some(HolderCategory::Business));
+        assert!(account.verification_status.as_ref().unwrap().is_pending());
+        assert!(!VerificationStatus::VerificationExpired.is_verified());
+        assert!(VerificationStatus::DatabaseMatched.is_verified());
+        assert_eq!(AccountSubtype::from("403B"), AccountSubtype::Plan403b);
+        assert_eq!(
+            AccountSubtype::from("crypto exchange"),
+            AccountSubtype::Unknown("crypto exchange".to_string())
+        );
+        assert_eq!(AccountType::Credit.to_string(), "credit");
+
+        let round_trip: Account =
+            serde_json::from_str(&serde_json::to_string(&account).unwrap()).unwrap();
+        assert_eq!(round_trip.account_id, account.account_id);
+        assert_eq!(round_trip.balances.current, account.balances.current);
+        assert_eq!(round_trip.r#type, "depository");
+    }
 
     #[tokio::test]
     async fn test_get_accounts() {
         let client = get_test_client();
         let sandbox_resp = client
-            .create_sandbox_public_token(SANDBOX_INSTITUTION, TEST_PRODUCTS)
+            .create_sandbox_public_token(SANDBOX_INSTITUTION, TEST_PRODUCTS, None)
             .await
             .unwrap();
         let token_resp = client
@@ -158,7 +539,7 @@ mod tests {
             .await
             .unwrap();
         assert_ne!(resp.accounts.len(), 0);
-        assert_eq!(resp.item.item_id.is_empty(), false);
+        assert!(!resp.item.item_id.is_empty());
 
         let resp = client
             .get_accounts(
@@ -170,14 +551,146 @@ mod tests {
             .await
             .unwrap();
         assert_ne!(resp.accounts.len(), 0);
-        assert_eq!(resp.item.item_id.is_empty(), false);
+        assert!(!resp.item.item_id.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_accounts_for_items() {
+        let (client, transport) = crate::client::tests::get_mock_client();
+        transport.push_json(
+            "accounts/get",
+            reqwest::StatusCode::OK,
+            serde_json::json!({
+                "request_id": "bkVE1BHWMAZ9Rnr",
+                "accounts": [],
+                "item": {
+                    "item_id": "Ed6bjNrDLJfGvZWwnkQlfxwoNz54B5C97ejBr",
+                    "update_type": "background"
+                }
+            }),
+        );
+        transport.push_json(
+            "accounts/get",
+            reqwest::StatusCode::BAD_REQUEST,
+            serde_json::json!({
+                "error_type": "INVALID_INPUT",
+                "error_code": "INVALID_ACCESS_TOKEN",
+                "error_message": "provided access token is in an invalid format",
+                "display_message": null,
+                "request_id": "bkVE1BHWMAZ9Rnr"
+            }),
+        );
+
+        let results = client
+            .get_accounts_for_items(&["access-sandbox-1", "access-sandbox-2"], None)
+            .await;
+        let tokens: Vec<&str> = results.iter().map(|(token, _)| token.as_str()).collect();
+        assert_eq!(tokens, vec!["access-sandbox-1", "access-sandbox-2"]);
+        assert_eq!(results.iter().filter(|(_, resp)| resp.is_ok()).count(), 1);
+        assert_eq!(transport.requests().len(), 2);
+    }
+
+    #[test]
+    fn test_total_by_currency() {
+        let resp: GetBalancesResponse = serde_json::from_value(serde_json::json!({
+            "request_id": "qk5Bxes3gDfv4F2",
+            "accounts": [
+                merge(
+                    account_fixture("usd_depository", "depository", None),
+                    serde_json::json!({"balances": {"current": 1000}}),
+                ),
+                merge(
+                    account_fixture("usd_credit", "credit", None),
+                    serde_json::json!({"balances": {"current": 300}}),
+                ),
+                merge(
+                    account_fixture("eur_depository", "depository", None),
+                    serde_json::json!({"balances": {"current": 200, "iso_currency_code": "EUR"}}),
+                ),
+                merge(
+                    account_fixture("btc_investment", "investment", None),
+                    serde_json::json!({"balances": {"current": 2, "iso_currency_code": null, "unofficial_currency_code": "BTC"}}),
+                ),
+                merge(
+                    account_fixture("no_currency", "depository", None),
+                    serde_json::json!({"balances": {"current": 50, "iso_currency_code": null}}),
+                ),
+            ]
+        }))
+        .unwrap();
+
+        let totals = resp.total_by_currency(false);
+        assert_eq!(totals.len(), 3);
+        assert_eq!(totals["USD"], Money::from(1300));
+        assert_eq!(totals["EUR"], Money::from(200));
+        assert_eq!(totals["BTC"], Money::from(2));
+
+        let totals = resp.total_by_currency(true);
+        assert_eq!(totals["USD"], Money::from(700));
+    }
+
+    #[test]
+    fn test_filter_accounts() {
+        let resp: GetAccountsResponse = serde_json::from_value(serde_json::json!({
+            "request_id": "bkVE1BHWMAZ9Rnr",
+            "accounts": [
+                account_fixture("checking", "depository", Some("checking")),
+                account_fixture("savings", "depository", Some("savings")),
+                account_fixture("credit", "credit", Some("credit card")),
+            ],
+            "item": {
+                "item_id": "Ed6bjNrDLJfGvZWwnkQlfxwoNz54B5C97ejBr",
+                "institution_id": "ins_109508",
+                "webhook": null,
+                "error": null,
+                "available_products": [],
+                "billed_products": ["auth"],
+                "consent_expiration_time": null,
+                "update_type": "background"
+            }
+        }))
+        .unwrap();
+
+        let depository = resp.filter_by_type(AccountType::Depository);
+        assert_eq!(depository.len(), 2);
+        assert_eq!(depository[1].account_id, "savings");
+        let credit_cards = resp.filter_by_subtype(AccountSubtype::CreditCard);
+        assert_eq!(credit_cards.len(), 1);
+        assert_eq!(credit_cards[0].account_id, "credit");
+        assert!(resp.filter_by_type(AccountType::Loan).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_balances_min_last_updated_datetime() {
+        let (client, transport) = get_mock_client();
+        transport.push_json(
+            "accounts/balance/get",
+            reqwest::StatusCode::OK,
+            serde_json::json!({"request_id": "1", "accounts": []}),
+        );
+        client
+            .get_balances(
+                "access-sandbox-123",
+                Some(GetBalancesOptions {
+                    min_last_updated_datetime: Some("2021-03-01T00:00:00Z".parse().unwrap()),
+                    ..Default::default()
+                }),
+            )
+            .await
+            .unwrap();
+        let body: serde_json::Value =
+            serde_json::from_slice(&transport.requests()[0].body).unwrap();
+        assert_eq!(
+            body["options"],
+            serde_json::json!({"min_last_updated_datetime": "2021-03-01T00:00:00Z"})
+        );
     }
 
     #[tokio::test]
     async fn test_get_balances() {
         let client = get_test_client();
         let sandbox_resp = client
-            .create_sandbox_public_token(SANDBOX_INSTITUTION, TEST_PRODUCTS)
+            .create_sandbox_public_token(SANDBOX_INSTITUTION, TEST_PRODUCTS, None)
             .await
             .unwrap();
         let token_resp = client
@@ -196,6 +709,7 @@ mod tests {
                 &token_resp.access_token,
                 Some(GetBalancesOptions {
                     account_ids: Some(&[&resp.accounts[0].account_id]),
+                    ..Default::default()
                 }),
             )
             .await
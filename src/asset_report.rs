@@ -0,0 +1,443 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::accounts::AccountBalances;
+use crate::client::Client;
+use crate::errors::Result;
+use crate::identity::Identity;
+use crate::types::Money;
+
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct AssetReportUser<'a> {
+    /// An identifier you determine and submit for the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_user_id: Option<&'a str>,
+    /// The user's first name. Required for the Fannie Mae Day 1 Certainty™ program.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<&'a str>,
+    /// The user's middle name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub middle_name: Option<&'a str>,
+    /// The user's last name. Required for the Fannie Mae Day 1 Certainty™ program.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<&'a str>,
+    /// The user's Social Security Number. Required for the Fannie Mae Day 1 Certainty™ program.
+    /// Format: "ddd-dd-dddd"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ssn: Option<&'a str>,
+    /// The user's phone number, in E.164 format: +{countrycode}{number}.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<&'a str>,
+    /// The user's email address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+struct CreateAssetReportRequest<'a> {
+    client_id: &'a str,
+    secret: &'a str,
+    access_tokens: &'a [&'a str],
+    days_requested: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    options: Option<CreateAssetReportOptions<'a>>,
+}
+
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct CreateAssetReportOptions<'a> {
+    /// Client-generated identifier, which can be used by lenders to track loan applications.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_report_id: Option<&'a str>,
+    /// URL to which Plaid will send Assets webhooks, for example when the requested Asset Report is ready.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webhook: Option<&'a str>,
+    /// The user object allows you to provide additional information about the user to be appended to the Asset Report.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<AssetReportUser<'a>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CreateAssetReportResponse {
+    /// A unique identifier for the request, which can be used for troubleshooting. This identifier, like all Plaid identifiers, is case sensitive.
+    pub request_id: String,
+    /// A token that can be provided to endpoints such as /asset_report/get or /asset_report/pdf/get to fetch or update an Asset Report.
+    pub asset_report_token: String,
+    /// A unique ID identifying an Asset Report. Like all Plaid identifiers, this ID is case sensitive.
+    pub asset_report_id: String,
+}
+
+#[derive(Serialize)]
+struct GetAssetReportRequest<'a> {
+    client_id: &'a str,
+    secret: &'a str,
+    asset_report_token: &'a str,
+    include_insights: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AssetReportUserInfo {
+    /// An identifier you determine and submit for the user.
+    pub client_user_id: Option<String>,
+    /// The user's first name.
+    pub first_name: Option<String>,
+    /// The user's middle name.
+    pub middle_name: Option<String>,
+    /// The user's last name.
+    pub last_name: Option<String>,
+    /// The user's Social Security Number.
+    pub ssn: Option<String>,
+    /// The user's phone number.
+    pub phone_number: Option<String>,
+    /// The user's email address.
+    pub email: Option<String>,
+}
+
+/// An object representing the balance held by an account in the past.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct HistoricalBalance {
+    /// The total amount of funds in the account, calculated from the current balance in the balance object by subtracting inflows and adding back outflows according to the posted date of each transaction.
+    pub current: Money,
+    /// The date of the calculated historical balance.
+    pub date: NaiveDate,
+    /// The ISO-4217 currency code of the balance. Always null if unofficial_currency_code is non-null.
+    pub iso_currency_code: Option<String>,
+    /// The unofficial currency code associated with the balance. Always null if iso_currency_code is non-null.
+    pub unofficial_currency_code: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AssetReportTransaction {
+    /// The unique ID of the transaction. Like all Plaid identifiers, the transaction_id is case sensitive.
+    pub transaction_id: String,
+    /// The ID of the account in which this transaction occurred.
+    pub account_id: String,
+    /// The settled value of the transaction, denominated in the account's currency. Positive values when money moves out of the account; negative values when money moves in.
+    pub amount: Money,
+    /// For pending transactions, the date that the transaction occurred; for posted transactions, the date that the transaction posted.
+    pub date: NaiveDate,
+    /// The string returned by the financial institution to describe the transaction.
+    pub original_description: Option<String>,
+    /// When true, identifies the transaction as pending or unsettled.
+    pub pending: bool,
+    /// The ISO-4217 currency code of the transaction. Always null if unofficial_currency_code is non-null.
+    pub iso_currency_code: Option<String>,
+    /// The unofficial currency code associated with the transaction. Always null if iso_currency_code is non-null.
+    pub unofficial_currency_code: Option<String>,
+    /// A hierarchical array of the categories to which this transaction belongs. Only returned when insights are included.
+    pub category: Option<Vec<String>>,
+    /// The ID of the category to which this transaction belongs. Only returned when insights are included.
+    pub category_id: Option<String>,
+    /// The merchant name or transaction description. Only returned when insights are included.
+    pub name: Option<String>,
+    /// The merchant name, as extracted by Plaid from the name field. Only returned when insights are included.
+    pub merchant_name: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AssetReportAccount {
+    /// Plaid’s unique identifier for the account.
+    pub account_id: String,
+    /// A set of fields describing the balance for an account.
+    pub balances: AccountBalances,
+    /// The last 2-4 alphanumeric characters of an account's official account number.
+    pub mask: Option<String>,
+    /// The name of the account, either assigned by the user or by the financial institution itself
+    pub name: String,
+    /// The official name of the account as given by the financial institution
+    pub official_name: Option<String>,
+    /// Possible values: investment, credit, depository, loan, brokerage, other
+    pub r#type: String,
+    /// The account subtype.
+    pub subtype: Option<String>,
+    /// The duration of transaction history available for this Item, typically defined as the time since the date of the earliest transaction in that account.
+    pub days_available: f64,
+    /// Transaction history associated with the account.
+    #[serde(default)]
+    pub transactions: Vec<AssetReportTransaction>,
+    /// Calculated data about the historical balances on the account.
+    #[serde(default)]
+    pub historical_balances: Vec<HistoricalBalance>,
+    /// Data returned by the financial institution about the account owner or owners.
+    #[serde(default)]
+    pub owners: Vec<Identity>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AssetReportItem {
+    /// The item_id of the Item associated with this webhook, warning, or error
+    pub item_id: String,
+    /// The full financial institution name associated with the Item.
+    pub institution_name: String,
+    /// The id of the financial institution associated with the Item.
+    pub institution_id: String,
+    /// The date and time when this Item’s data was last retrieved from the financial institution.
+    pub date_last_updated: DateTime<Utc>,
+    /// Data about each of the accounts open on the Item.
+    pub accounts: Vec<AssetReportAccount>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AssetReport {
+    /// A unique ID identifying an Asset Report. Like all Plaid identifiers, this ID is case sensitive.
+    pub asset_report_id: String,
+    /// An identifier you determine and submit for the Asset Report.
+    pub client_report_id: Option<String>,
+    /// The date and time when the Asset Report was created.
+    pub date_generated: DateTime<Utc>,
+    /// The duration of transaction history you requested
+    pub days_requested: f64,
+    /// The user object allows you to provide additional information about the user to be appended to the Asset Report.
+    pub user: AssetReportUserInfo,
+    /// Data returned by Plaid about each of the Items included in the Asset Report.
+    pub items: Vec<AssetReportItem>,
+}
+
+/// The underlying error that caused a warning.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct WarningCause {
+    /// A broad categorization of the error.
+    pub error_type: String,
+    /// The particular error code.
+    pub error_code: String,
+    /// A developer-friendly representation of the error code.
+    pub error_message: String,
+    /// A user-friendly representation of the error code.
+    pub display_message: Option<String>,
+    /// The item_id of the Item associated with this warning.
+    pub item_id: Option<String>,
+}
+
+/// A warning about a problem encountered while generating the Asset Report.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AssetReportWarning {
+    /// The warning type, which will always be ASSET_REPORT_WARNING
+    pub warning_type: String,
+    /// The warning code identifies a specific kind of warning.
+    /// Possible values: OWNERS_UNAVAILABLE
+    pub warning_code: String,
+    /// An error object and associated item_id used to identify a specific Item and error when a batch operation operating on multiple Items has encountered an error in one of the Items.
+    pub cause: Option<WarningCause>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GetAssetReportResponse {
+    /// A unique identifier for the request, which can be used for troubleshooting. This identifier, like all Plaid identifiers, is case sensitive.
+    pub request_id: String,
+    /// An object representing an Asset Report
+    pub report: AssetReport,
+    /// If the Asset Report generation was successful but identity information cannot be returned, this array will contain information about the errors causing identity information to be missing
+    #[serde(default)]
+    pub warnings: Vec<AssetReportWarning>,
+}
+
+#[derive(Serialize)]
+struct GetAssetReportPdfRequest<'a> {
+    client_id: &'a str,
+    secret: &'a str,
+    asset_report_token: &'a str,
+}
+
+#[derive(Serialize)]
+struct RemoveAssetReportRequest<'a> {
+    client_id: &'a str,
+    secret: &'a str,
+    asset_report_token: &'a str,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RemoveAssetReportResponse {
+    /// A unique identifier for the request, which can be used for troubleshooting. This identifier, like all Plaid identifiers, is case sensitive.
+    pub request_id: String,
+    /// true if the Asset Report was successfully removed.
+    pub removed: bool,
+}
+
+impl Client {
+    /// Create an Asset Report.
+    ///
+    /// The /asset_report/create endpoint initiates the process of creating an Asset Report, which can then be retrieved by passing the asset_report_token return value to the /asset_report/get or /asset_report/pdf/get endpoints.
+    ///
+    /// The Asset Report takes some time to be created and is not available immediately after calling /asset_report/create. When the Asset Report is ready to be retrieved using /asset_report/get or /asset_report/pdf/get, Plaid will fire a PRODUCT_READY webhook.
+    ///
+    /// * `access_tokens` - An array of access tokens corresponding to the Items that will be included in the report. The assets product must have been initialized for the Items during link.
+    /// * `days_requested` - The maximum integer number of days of history to include in the Asset Report. Maximum: 730
+    /// * `options` - An optional object to filter /asset_report/create results.
+    pub async fn create_asset_report<'a>(
+        &self,
+        access_tokens: &[&str],
+        days_requested: i32,
+        options: Option<CreateAssetReportOptions<'a>>,
+    ) -> Result<CreateAssetReportResponse> {
+        self.send_request(
+            "asset_report/create",
+            &CreateAssetReportRequest {
+                client_id: self.client_id(),
+                secret: self.secret(),
+                access_tokens,
+                days_requested,
+                options,
+            },
+        )
+        .await
+    }
+
+    /// Retrieve an Asset Report.
+    ///
+    /// The /asset_report/get endpoint retrieves the Asset Report in JSON format. Before calling /asset_report/get, you must first create the Asset Report using /asset_report/create and then wait for the PRODUCT_READY webhook to fire, indicating that the Report is ready to be retrieved.
+    ///
+    /// * `asset_report_token` - A token that can be provided to endpoints such as /asset_report/get or /asset_report/pdf/get to fetch or update an Asset Report.
+    /// * `include_insights` - true if you would like to retrieve the Asset Report with Insights, false otherwise.
+    pub async fn get_asset_report(
+        &self,
+        asset_report_token: &str,
+        include_insights: bool,
+    ) -> Result<GetAssetReportResponse> {
+        self.send_request(
+            "asset_report/get",
+            &GetAssetReportRequest {
+                client_id: self.client_id(),
+                secret: self.secret(),
+                asset_report_token,
+                include_insights,
+            },
+        )
+        .await
+    }
+
+    /// Retrieve a PDF Asset Report.
+    ///
+    /// The /asset_report/pdf/get endpoint retrieves the Asset Report in PDF format. Before calling /asset_report/pdf/get, you must first create the Asset Report using /asset_report/create and then wait for the PRODUCT_READY webhook to fire, indicating that the Report is ready to be retrieved. Returns the bytes of the PDF file.
+    ///
+    /// * `asset_report_token` - A token that can be provided to endpoints such as /asset_report/get or /asset_report/pdf/get to fetch or update an Asset Report.
+    pub async fn get_asset_report_pdf(&self, asset_report_token: &str) -> Result<Vec<u8>> {
+        self.send_request_raw(
+            "asset_report/pdf/get",
+            &GetAssetReportPdfRequest {
+                client_id: self.client_id(),
+                secret: self.secret(),
+                asset_report_token,
+            },
+        )
+        .await
+    }
+
+    /// Delete an Asset Report.
+    ///
+    /// The /asset_report/remove endpoint allows you to remove an Asset Report. Removing an Asset Report invalidates its asset_report_token, meaning you will no longer be able to use it to access Report data or create new Audit Copies.
+    ///
+    /// * `asset_report_token` - A token that can be provided to endpoints such as /asset_report/get or /asset_report/pdf/get to fetch or update an Asset Report.
+    pub async fn remove_asset_report(
+        &self,
+        asset_report_token: &str,
+    ) -> Result<RemoveAssetReportResponse> {
+        self.send_request(
+            "asset_report/remove",
+            &RemoveAssetReportRequest {
+                client_id: self.client_id(),
+                secret: self.secret(),
+                asset_report_token,
+            },
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::tests::{get_mock_client, get_test_client, SANDBOX_INSTITUTION};
+    use crate::errors::{Error, PlaidErrorCode};
+    use crate::transport::TransportResponse;
+    use crate::types::Product;
+    use reqwest::header::HeaderMap;
+    use reqwest::StatusCode;
+
+    #[tokio::test]
+    async fn test_get_asset_report_pdf() {
+        let (client, transport) = get_mock_client();
+        transport.push_response(
+            "asset_report/pdf/get",
+            TransportResponse {
+                status: StatusCode::OK,
+                headers: HeaderMap::new(),
+                body: b"%PDF-1.4".to_vec(),
+            },
+        );
+        transport.push_json(
+            "asset_report/pdf/get",
+            StatusCode::BAD_REQUEST,
+            serde_json::json!({
+                "request_id": "9rbNNDm4ADsAUUu",
+                "error_type": "ASSET_REPORT_ERROR",
+                "error_code": "PRODUCT_NOT_READY",
+                "error_message": "the requested product is not yet ready",
+                "display_message": null
+            }),
+        );
+
+        let pdf = client
+            .get_asset_report_pdf("assets-sandbox-6f12f5bb-22dd-4855-b918-f47ec439198a")
+            .await
+            .unwrap();
+        assert_eq!(pdf, b"%PDF-1.4");
+
+        match client
+            .get_asset_report_pdf("assets-sandbox-6f12f5bb-22dd-4855-b918-f47ec439198a")
+            .await
+        {
+            Err(Error::Plaid(err)) => assert_eq!(err.code(), PlaidErrorCode::ProductNotReady),
+            resp => panic!("unexpected response: {:?}", resp),
+        }
+
+        let body: serde_json::Value =
+            serde_json::from_slice(&transport.requests()[0].body).unwrap();
+        assert_eq!(
+            body["asset_report_token"],
+            "assets-sandbox-6f12f5bb-22dd-4855-b918-f47ec439198a"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_asset_report() {
+        let client = get_test_client();
+        let sandbox_resp = client
+            .create_sandbox_public_token(SANDBOX_INSTITUTION, &[Product::Assets], None)
+            .await
+            .unwrap();
+        let token_resp = client
+            .exchange_public_token(&sandbox_resp.public_token)
+            .await
+            .unwrap();
+
+        let create_resp = client
+            .create_asset_report(&[&token_resp.access_token], 30, None)
+            .await
+            .unwrap();
+        assert!(!create_resp.asset_report_token.is_empty());
+
+        let mut resp = client
+            .get_asset_report(&create_resp.asset_report_token, false)
+            .await;
+        let mut attempts = 1;
+        while let Err(err) = &resp {
+            match err {
+                Error::Plaid(plaid_err)
+                    if plaid_err.code() == PlaidErrorCode::ProductNotReady && attempts < 20 => {}
+                _ => panic!("unexpected error after {} attempts: {}", attempts, err),
+            }
+            attempts += 1;
+            tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+            resp = client
+                .get_asset_report(&create_resp.asset_report_token, false)
+                .await;
+        }
+        let resp = resp.unwrap();
+        assert_eq!(resp.report.asset_report_id, create_resp.asset_report_id);
+        assert_ne!(resp.report.items.len(), 0);
+
+        let remove_resp = client
+            .remove_asset_report(&create_resp.asset_report_token)
+            .await
+            .unwrap();
+        assert!(remove_resp.removed);
+    }
+}
@@ -20,7 +20,7 @@ pub struct GetAuthOptions<'a> {
     pub account_ids: Option<&'a [&'a str]>,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct GetAuthResponse {
     /// A unique identifier for the request, which can be used for troubleshooting. This identifier, like all Plaid identifiers, is case sensitive.
     pub request_id: String,
@@ -32,20 +32,54 @@ pub struct GetAuthResponse {
     pub item: Item,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct AccountNumberCollection {
     /// An array of ACH numbers identifying accounts.
+    #[serde(default)]
     pub ach: Vec<ACHNumber>,
     ///An array of EFT numbers identifying accounts.
+    #[serde(default)]
     pub eft: Vec<EFTNumber>,
     /// An array of IBAN numbers identifying accounts.
+    #[serde(default)]
     pub international: Vec<IBANNumber>,
     /// An array of BACS numbers identifying accounts.
+    #[serde(default)]
     pub bacs: Vec<BACSNumber>,
 }
 
+impl AccountNumberCollection {
+    /// Returns the numbers identifying a single account.
+    ///
+    /// * `account_id` - The Plaid account ID to look up.
+    pub fn for_account(&self, account_id: &str) -> AccountNumbers<'_> {
+        AccountNumbers {
+            ach: self.ach.iter().find(|n| n.account_id == account_id),
+            eft: self.eft.iter().find(|n| n.account_id == account_id),
+            international: self
+                .international
+                .iter()
+                .find(|n| n.account_id == account_id),
+            bacs: self.bacs.iter().find(|n| n.account_id == account_id),
+        }
+    }
+}
+
+/// The numbers identifying a single account, returned by [`AccountNumberCollection::for_account`].
+#[derive(Debug, Clone, Copy)]
+pub struct AccountNumbers<'a> {
+    /// The ACH numbers of the account, if any.
+    pub ach: Option<&'a ACHNumber>,
+    /// The EFT numbers of the account, if any.
+    pub eft: Option<&'a EFTNumber>,
+    /// The IBAN numbers of the account, if any.
+    pub international: Option<&'a IBANNumber>,
+    /// The BACS numbers of the account, if any.
+    pub bacs: Option<&'a BACSNumber>,
+}
+
 /// An array of ACH numbers identifying accounts.
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ACHNumber {
     /// The Plaid account ID associated with the account numbers
     pub account_id: String,
@@ -58,7 +92,7 @@ pub struct ACHNumber {
 }
 
 /// An array of EFT numbers identifying accounts.
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct EFTNumber {
     /// The Plaid account ID associated with the account numbers
     pub account_id: String,
@@ -71,7 +105,7 @@ pub struct EFTNumber {
 }
 
 /// An array of IBAN numbers identifying accounts.
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct IBANNumber {
     /// The Plaid account ID associated with the account numbers
     pub account_id: String,
@@ -82,7 +116,7 @@ pub struct IBANNumber {
 }
 
 /// An array of BACS numbers identifying accounts.
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct BACSNumber {
     /// The Plaid account ID associated with the account numbers
     pub account_id: String,
@@ -107,8 +141,8 @@ impl Client {
         self.send_request(
             "auth/get",
             &GetAuthRequest {
-                client_id: &self.client_id,
-                secret: &self.secret,
+                client_id: self.client_id(),
+                secret: self.secret(),
                 access_token,
                 options,
             },
@@ -122,11 +156,32 @@ mod tests {
     use super::*;
     use crate::client::tests::{get_test_client, SANDBOX_INSTITUTION, TEST_PRODUCTS};
 
+    #[test]
+    fn test_for_account() {
+        let numbers: AccountNumberCollection = serde_json::from_value(serde_json::json!({
+            "ach": [
+                {"account_id": "vzeNDwK7KQIm4yEog683uElbp9GRLEFXGK98D", "account": "1111222233330000", "routing": "011401533", "wire_routing": "021000021"},
+                {"account_id": "6PdjjRP6LmugpBy5NgQvUqpRXMWxzktg3rwrk", "account": "1111222233331111", "routing": "011401533", "wire_routing": null}
+            ],
+            "eft": [],
+            "international": [],
+            "bacs": [
+                {"account_id": "6PdjjRP6LmugpBy5NgQvUqpRXMWxzktg3rwrk", "account": "31926819", "sort_code": "601613"}
+            ]
+        }))
+        .unwrap();
+        let account = numbers.for_account("6PdjjRP6LmugpBy5NgQvUqpRXMWxzktg3rwrk");
+        assert_eq!(account.ach.unwrap().account, "1111222233331111");
+        assert_eq!(account.bacs.unwrap().sort_code, "601613");
+        assert!(account.eft.is_none());
+        assert!(numbers.for_account("unknown").ach.is_none());
+    }
+
     #[tokio::test]
     async fn test_get_auth() {
         let client = get_test_client();
         let sandbox_resp = client
-            .create_sandbox_public_token(SANDBOX_INSTITUTION, TEST_PRODUCTS)
+            .create_sandbox_public_token(SANDBOX_INSTITUTION, TEST_PRODUCTS, None)
             .await
             .unwrap();
         let token_resp = client
@@ -139,7 +194,7 @@ mod tests {
             .await
             .unwrap();
         assert_ne!(resp.accounts.len(), 0);
-        assert_eq!(resp.item.item_id.is_empty(), false);
+        assert!(!resp.item.item_id.is_empty());
 
         let resp = client
             .get_auth(
@@ -151,6 +206,6 @@ mod tests {
             .await
             .unwrap();
         assert_ne!(resp.accounts.len(), 0);
-        assert_eq!(resp.item.item_id.is_empty(), false);
+        assert!(!resp.item.item_id.is_empty());
     }
 }
@@ -6,7 +6,7 @@ use crate::errors::Result;
 #[derive(Serialize)]
 struct GetCategoriesRequest {}
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct GetCategoriesResponse {
     /// An array of all of the transaction categories used by Plaid.
     pub categories: Vec<Category>,
@@ -14,7 +14,7 @@ pub struct GetCategoriesResponse {
     pub request_id: String,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Category {
     /// An identifying number for the category. category_id is a Plaid-specific identifier and does not necessarily correspond to merchant category codes.
     pub category_id: String,
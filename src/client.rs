@@ -1,61 +1,352 @@
-use reqwest;
+use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
 use reqwest::Url;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
 
-use crate::errors::{ErrorResponse, PlaidError, Result};
+use crate::errors::{EnvError, Error, ErrorResponse, PlaidError, Result};
+use crate::transport::{ReqwestTransport, Transport, TransportRequest, TransportResponse};
+use crate::webhooks::WebhookKeyCache;
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Serialize, Debug, Copy, Clone)]
+#[serde(rename_all = "lowercase")]
 pub enum Environment {
     Sandbox,
     Development,
     Production,
 }
 
+impl FromStr for Environment {
+    type Err = EnvError;
+
+    /// Parse an environment name. Accepts `sandbox`, `development` or `production`, case-insensitively.
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match &s.to_lowercase()[..] {
+            "sandbox" => Ok(Environment::Sandbox),
+            "development" => Ok(Environment::Development),
+            "production" => Ok(Environment::Production),
+            _ => Err(EnvError::InvalidEnvironment(s.to_string())),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Environment {
+    /// Deserializes an environment name with the same rules as `Environment::from_str`.
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(|_| {
+            serde::de::Error::unknown_variant(&s, &["sandbox", "development", "production"])
+        })
+    }
+}
+
+/// Run a call with a deadline, returning `Error::Timeout` if it does not complete in time.
+///
+/// Use this to give individual calls a different timeout than the one configured on the HTTP client, e.g. a short one for balance lookups and a long one for asset report creation. The deadline covers the whole call, including any rate limit retries. Must be run within a Tokio runtime with the time driver enabled.
+///
+/// * `timeout` - The maximum time the call may take.
+/// * `future` - The call, e.g. `client.get_balances(access_token, None)`.
+pub async fn with_timeout<T, F>(timeout: Duration, future: F) -> Result<T>
+where
+    F: std::future::Future<Output = Result<T>>,
+{
+    match tokio::time::timeout(timeout, future).await {
+        Ok(resp) => resp,
+        Err(_) => Err(Error::Timeout(timeout)),
+    }
+}
+
+/// A response with no fields of interest, for endpoints that may respond with an empty body or `204 No Content`.
+///
+/// `()` can also be used as the response type of such endpoints.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct EmptyResponse {
+    /// A unique identifier for the request, which can be used for troubleshooting. None if the response has no body.
+    #[serde(default)]
+    pub request_id: Option<String>,
+}
+
+/// Deserializes a successful response body. An empty body is read as `null`, or failing that as `{}`, so that response types such as `()` or `EmptyResponse` accept it.
+fn parse_body<U>(body: &[u8]) -> Result<U>
+where
+    U: for<'de> serde::Deserialize<'de>,
+{
+    if !body.iter().all(u8::is_ascii_whitespace) {
+        return Ok(serde_json::from_slice(body)?);
+    }
+    serde_json::from_value(serde_json::Value::Null)
+        .or_else(|err| serde_json::from_value(serde_json::json!({})).map_err(|_| Error::from(err)))
+}
+
+/// HTTP metadata of a successful response, returned by `Client::send_request_with_meta`.
 #[derive(Debug, Clone)]
+pub struct ResponseMeta {
+    /// The HTTP status code of the response.
+    pub status: reqwest::StatusCode,
+    /// The HTTP headers of the response.
+    pub headers: reqwest::header::HeaderMap,
+}
+
+impl ResponseMeta {
+    /// Returns the value of a response header, if it is present and valid UTF-8.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).and_then(|value| value.to_str().ok())
+    }
+}
+
+/// Controls how requests that fail due to rate limiting are retried.
+#[derive(Debug, Copy, Clone)]
+pub struct RetryPolicy {
+    /// The maximum number of retries after the initial attempt.
+    pub max_retries: u32,
+    /// The delay before the first retry. Each subsequent retry doubles the previous delay.
+    pub base_delay: Duration,
+}
+
+impl RetryPolicy {
+    /// Returns the delay to wait before the given retry attempt (starting at 0).
+    pub fn delay_for(&self, attempt: u32) -> Duration {
+        2u32.checked_pow(attempt)
+            .and_then(|factor| self.base_delay.checked_mul(factor))
+            .unwrap_or(Duration::MAX)
+    }
+}
+
+/// A Plaid API client.
+///
+/// The credentials, configuration and connection pool are shared behind an `Arc`, so cloning a `Client` is cheap and every clone reuses the same connections. Create one client and clone it into each task instead of creating a client per task.
+///
+/// The `Debug` output never contains the secret, and only the last four characters of the client_id, so a client can be logged safely.
+#[derive(Clone)]
 pub struct Client {
-    client: reqwest::Client,
-    pub client_id: String,
-    pub secret: String,
+    inner: Arc<ClientInner>,
+}
+
+#[derive(Clone)]
+struct ClientInner {
+    transport: Arc<dyn Transport>,
+    client_id: String,
+    secret: String,
     environment: Environment,
+    retry_policy: Option<RetryPolicy>,
+    api_version: Option<String>,
+    base_url: Option<Url>,
+    webhook_keys: WebhookKeyCache,
+}
+
+impl fmt::Debug for Client {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let inner = &self.inner;
+        f.debug_struct("Client")
+            .field("transport", &inner.transport)
+            .field("client_id", &mask_client_id(&inner.client_id))
+            .field("secret", &"[redacted]")
+            .field("environment", &inner.environment)
+            .field("retry_policy", &inner.retry_policy)
+            .field("api_version", &inner.api_version)
+            .field("base_url", &inner.base_url)
+            .finish()
+    }
+}
+
+fn mask_client_id(client_id: &str) -> String {
+    let visible = client_id
+        .char_indices()
+        .rev()
+        .nth(3)
+        .map_or(0, |(index, _)| index);
+    format!(
+        "{}{}",
+        "*".repeat(client_id[..visible].chars().count()),
+        &client_id[visible..]
+    )
 }
 
 impl Client {
     /// Create a plaid client using the supplied credentials.
     ///
+    /// Responses are requested with `Accept-Encoding: gzip, deflate` and decompressed transparently.
+    ///
     /// * `client_id` - Your Plaid API client_id.
     /// * `secret` - Your Plaid API secret.
     /// * `environment` - The Plaid environment to use.
     pub fn new(client_id: String, secret: String, environment: Environment) -> Client {
-        Client {
-            client: reqwest::Client::builder().build().unwrap(),
+        Client::from_http_client(
+            client_id,
+            secret,
+            environment,
+            reqwest::Client::builder().build().unwrap(),
+        )
+    }
+
+    /// Start building a plaid client whose HTTP connections can be tuned.
+    ///
+    /// * `client_id` - Your Plaid API client_id.
+    /// * `secret` - Your Plaid API secret.
+    /// * `environment` - The Plaid environment to use.
+    pub fn builder(client_id: String, secret: String, environment: Environment) -> ClientBuilder {
+        ClientBuilder {
             client_id,
             secret,
             environment,
+            http_client: reqwest::Client::builder(),
         }
     }
 
+    /// Create a plaid client using the supplied credentials and a pre-built HTTP client.
+    ///
+    /// Use this to configure proxies, TLS roots, connection pooling or a custom user agent on the underlying `reqwest::Client`. Response compression is enabled by default; call `no_gzip()` and `no_deflate()` on the builder to turn it off.
+    ///
+    /// * `client_id` - Your Plaid API client_id.
+    /// * `secret` - Your Plaid API secret.
+    /// * `environment` - The Plaid environment to use.
+    /// * `http_client` - The HTTP client used to send requests.
+    pub fn from_http_client(
+        client_id: String,
+        secret: String,
+        environment: Environment,
+        http_client: reqwest::Client,
+    ) -> Client {
+        Client::from_transport(
+            client_id,
+            secret,
+            environment,
+            Arc::new(ReqwestTransport::new(http_client)),
+        )
+    }
+
+    /// Create a plaid client that sends its requests through the supplied transport.
+    ///
+    /// Use this to substitute a mock transport in tests; see `transport::MockTransport`.
+    ///
+    /// * `client_id` - Your Plaid API client_id.
+    /// * `secret` - Your Plaid API secret.
+    /// * `environment` - The Plaid environment to use.
+    /// * `transport` - The transport used to send requests.
+    pub fn from_transport(
+        client_id: String,
+        secret: String,
+        environment: Environment,
+        transport: Arc<dyn Transport>,
+    ) -> Client {
+        Client {
+            inner: Arc::new(ClientInner {
+                transport,
+                client_id,
+                secret,
+                environment,
+                retry_policy: None,
+                api_version: None,
+                base_url: None,
+                webhook_keys: Default::default(),
+            }),
+        }
+    }
+
+    /// Returns the Plaid API client_id used by this client.
+    pub fn client_id(&self) -> &str {
+        &self.inner.client_id
+    }
+
+    /// Returns the Plaid API secret used by this client.
+    pub fn secret(&self) -> &str {
+        &self.inner.secret
+    }
+
+    pub(crate) fn webhook_keys(&self) -> &WebhookKeyCache {
+        &self.inner.webhook_keys
+    }
+
+    /// Returns a client that uses different credentials but shares this client's transport and configuration.
+    ///
+    /// Use this in multi-tenant setups where Items belong to different Plaid accounts, so that every tenant reuses the same connection pool instead of building a client each.
+    ///
+    /// * `client_id` - The Plaid API client_id to use.
+    /// * `secret` - The Plaid API secret to use.
+    pub fn with_credentials(&self, client_id: String, secret: String) -> Client {
+        Client {
+            inner: Arc::new(ClientInner {
+                client_id,
+                secret,
+                ..(*self.inner).clone()
+            }),
+        }
+    }
+
+    /// Pin the Plaid API version used by this client.
+    ///
+    /// The version is sent as the `Plaid-Version` header on every request. When unset, no header is sent and Plaid uses the version configured for your account.
+    ///
+    /// * `api_version` - A dated Plaid API version, e.g. `2020-09-14`.
+    pub fn with_api_version(mut self, api_version: &str) -> Client {
+        Arc::make_mut(&mut self.inner).api_version = Some(api_version.to_string());
+        self
+    }
+
+    /// Send requests to a custom base URL instead of the Plaid host for the environment.
+    ///
+    /// Use this to route requests through an enterprise gateway or to point tests at a local mock server. Endpoint paths are appended to the URL, so `https://gateway.example.com/plaid` sends /accounts/get to `https://gateway.example.com/plaid/accounts/get`.
+    ///
+    /// Returns `Error::InvalidRequest` if the URL cannot be parsed or cannot be used as a base.
+    ///
+    /// * `base_url` - The URL to send requests to.
+    pub fn with_base_url(mut self, base_url: &str) -> Result<Client> {
+        let mut url = Url::parse(base_url).map_err(|err| {
+            Error::InvalidRequest(format!("invalid base URL {}: {}", base_url, err))
+        })?;
+        if url.cannot_be_a_base() {
+            return Err(Error::InvalidRequest(format!(
+                "invalid base URL {}: cannot be a base",
+                base_url
+            )));
+        }
+        if !url.path().ends_with('/') {
+            url.set_path(&format!("{}/", url.path()));
+        }
+        Arc::make_mut(&mut self.inner).base_url = Some(url);
+        Ok(self)
+    }
+
+    /// Retry requests that fail with RATE_LIMIT_EXCEEDED or HTTP 429 using exponential backoff.
+    ///
+    /// Other errors are returned immediately. Once `max_retries` is exhausted, the last error is returned.
+    ///
+    /// * `max_retries` - The maximum number of retries after the initial attempt.
+    /// * `base_delay` - The delay before the first retry. The delay doubles after every retry.
+    pub fn with_retry_policy(mut self, max_retries: u32, base_delay: Duration) -> Client {
+        Arc::make_mut(&mut self.inner).retry_policy = Some(RetryPolicy {
+            max_retries,
+            base_delay,
+        });
+        self
+    }
+
     /// Create a plaid client using credentials supplied from the environment.
     ///
     /// Credentials must be passed in `PLAID_CLIENT_ID`, `PLAID_SECRET` and `PLAID_ENVIRONMENT` environment variables.
     /// `PLAID_ENVIRONMENT` must be set to `SANDBOX`, `DEVELOPMENT` or `PRODUCTION`.
+    ///
+    /// Panics if a variable is missing or invalid. Use `try_from_env` to handle these cases instead.
     pub fn from_env() -> Client {
-        let plaid_environment = match &std::env::var("PLAID_ENVIRONMENT")
-        .expect("Missing environment variable: PLAID_ENVIRONMENT. Must be set to SANDBOX, DEVELOPMENT or PRODUCTION")
-        .to_string().to_lowercase()[..] {
-            "SANDBOX" => Environment::Sandbox,
-            "DEVELOPMENT" => Environment::Development,
-            "PRODUCTION" => Environment::Production,
-            s => panic!("Environment variable PLAID_ENVIRONMENT must be set to SANDBOX, DEVELOPMENT or PRODUCTION. Is actually {}", s),
-        };
+        Client::try_from_env().unwrap_or_else(|err| panic!("{}", err))
+    }
 
-        Client::new(
-            std::env::var("PLAID_CLIENT_ID")
-                .expect("Missing environment variable: PLAID_CLIENT_ID")
-                .to_string(),
-            std::env::var("PLAID_SECRET")
-                .expect("Missing environment variable: PLAID_SECRET")
-                .to_string(),
-            plaid_environment,
-        )
+    /// Create a plaid client using credentials supplied from the environment, returning an error instead of panicking.
+    ///
+    /// Credentials must be passed in `PLAID_CLIENT_ID`, `PLAID_SECRET` and `PLAID_ENVIRONMENT` environment variables.
+    /// `PLAID_ENVIRONMENT` must be set to `SANDBOX`, `DEVELOPMENT` or `PRODUCTION` (case-insensitive).
+    pub fn try_from_env() -> std::result::Result<Client, EnvError> {
+        let env_var = |name: &'static str| std::env::var(name).map_err(|_| EnvError::Missing(name));
+        Ok(Client::new(
+            env_var("PLAID_CLIENT_ID")?,
+            env_var("PLAID_SECRET")?,
+            env_var("PLAID_ENVIRONMENT")?.parse()?,
+        ))
     }
 
     pub async fn send_request<T, U>(&self, url: &str, req: &T) -> Result<U>
@@ -63,31 +354,223 @@ impl Client {
         T: serde::Serialize,
         U: for<'de> serde::Deserialize<'de>,
     {
-        let resp = self
-            .client
-            .post(self.get_host().join(url).unwrap())
-            .json(req)
-            .send()
-            .await?;
-        if resp.status() == reqwest::StatusCode::OK {
-            Ok(resp.json().await?)
+        self.send_request_with_key(url, req, None)
+            .await
+            .map(|(body, _)| body)
+    }
+
+    /// Send a request, returning the deserialized body along with the HTTP status and headers of the response.
+    pub async fn send_request_with_meta<T, U>(
+        &self,
+        url: &str,
+        req: &T,
+    ) -> Result<(U, ResponseMeta)>
+    where
+        T: serde::Serialize,
+        U: for<'de> serde::Deserialize<'de>,
+    {
+        self.send_request_with_key(url, req, None).await
+    }
+
+    /// Send a request with an idempotency key, so that retrying it does not execute it twice.
+    ///
+    /// The key is sent in the `Plaid-Idempotency-Key` header. Generate a unique key for each logical request, e.g. a UUID stored alongside your own record of it, and reuse it when retrying that request.
+    pub async fn send_request_idempotent<T, U>(
+        &self,
+        url: &str,
+        req: &T,
+        idempotency_key: &str,
+    ) -> Result<U>
+    where
+        T: serde::Serialize,
+        U: for<'de> serde::Deserialize<'de>,
+    {
+        self.send_request_with_key(url, req, Some(idempotency_key))
+            .await
+            .map(|(body, _)| body)
+    }
+
+    pub(crate) async fn send_request_with_key<T, U>(
+        &self,
+        url: &str,
+        req: &T,
+        idempotency_key: Option<&str>,
+    ) -> Result<(U, ResponseMeta)>
+    where
+        T: serde::Serialize,
+        U: for<'de> serde::Deserialize<'de>,
+    {
+        let resp = self.send_with_retry(url, req, idempotency_key).await?;
+        let body = parse_body(&resp.body)?;
+        Ok((
+            body,
+            ResponseMeta {
+                status: resp.status,
+                headers: resp.headers,
+            },
+        ))
+    }
+
+    /// Send a request with a deadline, returning `Error::Timeout` if it does not complete in time. See `with_timeout`.
+    pub async fn send_request_with_timeout<T, U>(
+        &self,
+        url: &str,
+        req: &T,
+        timeout: Duration,
+    ) -> Result<U>
+    where
+        T: serde::Serialize,
+        U: for<'de> serde::Deserialize<'de>,
+    {
+        with_timeout(timeout, self.send_request(url, req)).await
+    }
+
+    /// Send a request and return the raw response body, for endpoints such as /asset_report/pdf/get that do not respond with JSON.
+    ///
+    /// Errors are handled the same way as in `send_request`.
+    pub async fn send_request_raw<T>(&self, url: &str, req: &T) -> Result<Vec<u8>>
+    where
+        T: serde::Serialize,
+    {
+        self.send_with_retry(url, req, None)
+            .await
+            .map(|resp| resp.body)
+    }
+
+    async fn send_with_retry<T>(
+        &self,
+        url: &str,
+        req: &T,
+        idempotency_key: Option<&str>,
+    ) -> Result<TransportResponse>
+    where
+        T: serde::Serialize,
+    {
+        let mut attempt = 0;
+        loop {
+            match self.send_request_once(url, req, idempotency_key).await {
+                Err(err) if err.is_rate_limited() => match self.inner.retry_policy {
+                    Some(policy) if attempt < policy.max_retries => {
+                        tokio::time::sleep(policy.delay_for(attempt)).await;
+                        attempt += 1;
+                    }
+                    _ => return Err(err),
+                },
+                resp => return resp,
+            }
+        }
+    }
+
+    /// Serialize a request exactly as `send_request` would, without sending it, and return the JSON body.
+    ///
+    /// Use this in offline tests to assert the body built from your request structs. The body includes the client_id and secret.
+    ///
+    /// * `url` - The endpoint path, e.g. `link/token/create`.
+    /// * `req` - The request to serialize.
+    pub fn build_request_json<T>(&self, url: &str, req: &T) -> Result<serde_json::Value>
+    where
+        T: serde::Serialize,
+    {
+        let request = self.build_request(url, req, None)?;
+        Ok(serde_json::from_slice(&request.body)?)
+    }
+
+    fn build_request<T>(
+        &self,
+        url: &str,
+        req: &T,
+        idempotency_key: Option<&str>,
+    ) -> Result<TransportRequest>
+    where
+        T: serde::Serialize,
+    {
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        if let Some(api_version) = &self.inner.api_version {
+            headers.insert(
+                "Plaid-Version",
+                HeaderValue::from_str(api_version)
+                    .map_err(|err| Error::InvalidRequest(err.to_string()))?,
+            );
+        }
+        if let Some(idempotency_key) = idempotency_key {
+            headers.insert(
+                "Plaid-Idempotency-Key",
+                HeaderValue::from_str(idempotency_key)
+                    .map_err(|err| Error::InvalidRequest(err.to_string()))?,
+            );
+        }
+        let body = serde_json::to_vec(req).map_err(|err| Error::InvalidRequest(err.to_string()))?;
+        Ok(TransportRequest {
+            url: self.endpoint_url(url)?,
+            headers,
+            body,
+        })
+    }
+
+    /// Resolves an endpoint path against the host. A leading slash is ignored, so that it cannot replace the path of a custom base URL, and paths that would resolve outside the host's base URL are rejected.
+    fn endpoint_url(&self, path: &str) -> Result<Url> {
+        let host = self.get_host();
+        let url = host
+            .join(path.trim_start_matches('/'))
+            .map_err(|err| Error::InvalidRequest(format!("invalid endpoint {}: {}", path, err)))?;
+        if !url.as_str().starts_with(host.as_str()) {
+            return Err(Error::InvalidRequest(format!(
+                "invalid endpoint {}: resolves to {} outside of {}",
+                path, url, host
+            )));
+        }
+        Ok(url)
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "plaid.request", skip_all, fields(path = url))
+    )]
+    async fn send_request_once<T>(
+        &self,
+        url: &str,
+        req: &T,
+        idempotency_key: Option<&str>,
+    ) -> Result<TransportResponse>
+    where
+        T: serde::Serialize,
+    {
+        let request = self.build_request(url, req, idempotency_key)?;
+        #[cfg(feature = "tracing")]
+        let start = std::time::Instant::now();
+        let resp = self.inner.transport.send(request).await?;
+        #[cfg(feature = "tracing")]
+        trace_response(&resp, start.elapsed());
+        if resp.status.is_success() {
+            Ok(resp)
         } else {
-            let status_code = resp.status();
-            let err_resp: ErrorResponse = resp.json().await?;
+            let err_resp: ErrorResponse = match serde_json::from_slice(&resp.body) {
+                Ok(err_resp) => err_resp,
+                Err(_) => {
+                    return Err(Error::UnexpectedResponse {
+                        status: resp.status,
+                        body: String::from_utf8_lossy(&resp.body).into_owned(),
+                    })
+                }
+            };
             Err(PlaidError {
                 request_id: err_resp.request_id,
                 error_type: err_resp.error_type,
                 error_code: err_resp.error_code,
                 error_message: err_resp.error_message,
                 display_message: err_resp.display_message,
-                status_code: status_code,
+                status_code: resp.status,
             }
             .into())
         }
     }
 
     fn get_host(&self) -> Url {
-        match self.environment {
+        if let Some(base_url) = &self.inner.base_url {
+            return base_url.clone();
+        }
+        match self.inner.environment {
             Environment::Sandbox => Url::parse("https://sandbox.plaid.com/").unwrap(),
             Environment::Development => Url::parse("https://development.plaid.com/").unwrap(),
             Environment::Production => Url::parse("https://production.plaid.com/").unwrap(),
@@ -95,12 +578,83 @@ impl Client {
     }
 }
 
+/// Builds a [`Client`] with tuned connection pooling, returned by `Client::builder`.
+///
+/// The setters forward to the underlying `reqwest::ClientBuilder`. Use `Client::from_http_client` for settings that are not exposed here.
+pub struct ClientBuilder {
+    client_id: String,
+    secret: String,
+    environment: Environment,
+    http_client: reqwest::ClientBuilder,
+}
+
+impl fmt::Debug for ClientBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ClientBuilder")
+            .field("client_id", &mask_client_id(&self.client_id))
+            .field("secret", &"[redacted]")
+            .field("environment", &self.environment)
+            .field("http_client", &self.http_client)
+            .finish()
+    }
+}
+
+impl ClientBuilder {
+    /// Set the maximum number of idle connections kept open to the Plaid host.
+    ///
+    /// * `max` - The maximum number of idle connections. Defaults to no limit.
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> ClientBuilder {
+        self.http_client = self.http_client.pool_max_idle_per_host(max);
+        self
+    }
+
+    /// Set the TCP keepalive interval for connections to the Plaid host.
+    ///
+    /// * `interval` - The keepalive interval, or `None` to disable keepalive. Disabled by default.
+    pub fn tcp_keepalive<D: Into<Option<Duration>>>(mut self, interval: D) -> ClientBuilder {
+        self.http_client = self.http_client.tcp_keepalive(interval);
+        self
+    }
+
+    /// Build the client.
+    ///
+    /// Returns `Error::Request` if the HTTP client cannot be initialized, e.g. because the TLS backend fails to load.
+    pub fn build(self) -> Result<Client> {
+        Ok(Client::from_http_client(
+            self.client_id,
+            self.secret,
+            self.environment,
+            self.http_client.build()?,
+        ))
+    }
+}
+
+/// Emits the status, timing and Plaid request_id of a response. Only the request_id is read from the body.
+#[cfg(feature = "tracing")]
+fn trace_response(resp: &crate::transport::TransportResponse, elapsed: Duration) {
+    #[derive(serde::Deserialize)]
+    struct RequestId {
+        request_id: Option<String>,
+    }
+    let request_id = serde_json::from_slice::<RequestId>(&resp.body)
+        .ok()
+        .and_then(|body| body.request_id);
+    tracing::debug!(
+        status = resp.status.as_u16(),
+        elapsed_ms = elapsed.as_millis() as u64,
+        request_id = request_id.as_deref().unwrap_or(""),
+        "plaid response"
+    );
+}
+
 pub mod tests {
     use super::*;
+    use crate::types::Product;
 
     pub const SANDBOX_INSTITUTION: &str = "ins_109508";
     pub const SANDBOX_INSTITUTION_QUERY: &str = "Platypus";
-    pub const TEST_PRODUCTS: &[&str] = &["auth", "identity", "transactions"];
+    pub const TEST_PRODUCTS: &[Product] =
+        &[Product::Auth, Product::Identity, Product::Transactions];
 
     pub fn get_test_client() -> Client {
         Client::new(
@@ -113,4 +667,400 @@ pub mod tests {
             Environment::Sandbox,
         )
     }
+
+    /// Returns a sandbox client backed by a mock transport, for tests that must not touch the network.
+    #[cfg(test)]
+    pub fn get_mock_client() -> (Client, Arc<crate::transport::MockTransport>) {
+        let transport = Arc::new(crate::transport::MockTransport::new());
+        let client = Client::from_transport(
+            "client_id".to_string(),
+            "secret".to_string(),
+            Environment::Sandbox,
+            transport.clone(),
+        );
+        (client, transport)
+    }
+
+    #[test]
+    fn test_client_clone_is_shared() {
+        let (client, _) = get_mock_client();
+        let clone = client.clone();
+        assert!(Arc::ptr_eq(&client.inner, &clone.inner));
+        assert_eq!(clone.client_id(), "client_id");
+        assert_eq!(clone.secret(), "secret");
+
+        let versioned = clone.with_api_version("2020-09-14");
+        assert!(!Arc::ptr_eq(&client.inner, &versioned.inner));
+        assert!(client.inner.api_version.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_debug_redacts_credentials() {
+        let (client, transport) = get_mock_client();
+        let client =
+            client.with_credentials("5e2b6c1a9f3d".to_string(), "d3c4e8f1a2b7c9".to_string());
+        let debug = format!("{:?}", client);
+        assert!(debug.contains("********9f3d"));
+        assert!(!debug.contains("5e2b6c1a9f3d"));
+        assert!(!debug.contains("d3c4e8f1a2b7c9"));
+        assert!(debug.contains("[redacted]"));
+        assert_eq!(mask_client_id("abc"), "abc");
+
+        transport.push_json(
+            "item/remove",
+            reqwest::StatusCode::OK,
+            serde_json::json!({"request_id": "m8MDnv9okwxFNBV"}),
+        );
+        client.remove_item("access-sandbox-123").await.unwrap();
+        let request = &transport.requests()[0];
+        let debug = format!("{:?}", request);
+        assert!(debug.contains(&format!("<{} bytes>", request.body.len())));
+        assert!(!debug.contains("d3c4e8f1a2b7c9"));
+    }
+
+    #[test]
+    fn test_endpoint_url() {
+        let (client, _) = get_mock_client();
+        assert_eq!(
+            client.endpoint_url("item/get").unwrap().as_str(),
+            "https://sandbox.plaid.com/item/get"
+        );
+        let client = client
+            .with_base_url("https://gateway.example.com/plaid")
+            .unwrap();
+        assert_eq!(
+            client.endpoint_url("/item/get").unwrap().as_str(),
+            "https://gateway.example.com/plaid/item/get"
+        );
+        for path in &["../item/get", "https://example.com/item/get"] {
+            match client.endpoint_url(path) {
+                Err(Error::InvalidRequest(_)) => {}
+                other => panic!("unexpected result for {}: {:?}", path, other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_build_request_json() {
+        let (client, transport) = get_mock_client();
+        let filters = crate::link_token::AccountFilters {
+            depository: Some(crate::link_token::AccountSubtypeFilter {
+                account_subtypes: vec![crate::accounts::AccountSubtype::Checking],
+            }),
+            ..Default::default()
+        };
+        let body = client
+            .build_request_json("link/token/create", &filters)
+            .unwrap();
+        assert_eq!(
+            body,
+            serde_json::json!({"depository": {"account_subtypes": ["checking"]}})
+        );
+        assert!(transport.requests().is_empty());
+    }
+
+    #[test]
+    fn test_client_builder() {
+        let builder = Client::builder(
+            "client_id".to_string(),
+            "secret".to_string(),
+            Environment::Sandbox,
+        )
+        .pool_max_idle_per_host(4)
+        .tcp_keepalive(Duration::from_secs(60));
+        assert!(!format!("{:?}", builder).contains("\"secret\""));
+
+        let client = builder.build().unwrap();
+        assert_eq!(client.client_id(), "client_id");
+        assert_eq!(client.secret(), "secret");
+        assert_eq!(client.get_host().as_str(), "https://sandbox.plaid.com/");
+    }
+
+    #[tokio::test]
+    async fn test_with_credentials() {
+        let (client, transport) = get_mock_client();
+        let tenant = client.with_credentials("tenant_id".to_string(), "tenant_secret".to_string());
+        assert!(Arc::ptr_eq(
+            &client.inner.transport,
+            &tenant.inner.transport
+        ));
+        assert_eq!(client.client_id(), "client_id");
+
+        transport.push_json(
+            "item/remove",
+            reqwest::StatusCode::OK,
+            serde_json::json!({"request_id": "m8MDnv9okwxFNBV"}),
+        );
+        tenant.remove_item("access-sandbox-123").await.unwrap();
+        let body: serde_json::Value =
+            serde_json::from_slice(&transport.requests()[0].body).unwrap();
+        assert_eq!(body["client_id"], "tenant_id");
+        assert_eq!(body["secret"], "tenant_secret");
+    }
+
+    #[tokio::test]
+    async fn test_with_base_url() {
+        let (client, transport) = get_mock_client();
+        let client = client.with_base_url("http://localhost:8080/plaid").unwrap();
+        transport.push_json(
+            "plaid/item/remove",
+            reqwest::StatusCode::OK,
+            serde_json::json!({"request_id": "m8MDnv9okwxFNBV"}),
+        );
+        client.remove_item("access-sandbox-123").await.unwrap();
+        assert_eq!(
+            transport.requests()[0].url.as_str(),
+            "http://localhost:8080/plaid/item/remove"
+        );
+
+        for base_url in ["not a url", "mailto:support@plaid.com"] {
+            assert!(matches!(
+                client.clone().with_base_url(base_url),
+                Err(Error::InvalidRequest(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_parse_environment() {
+        assert!(matches!("sandbox".parse(), Ok(Environment::Sandbox)));
+        assert!(matches!(
+            "DEVELOPMENT".parse(),
+            Ok(Environment::Development)
+        ));
+        assert!(matches!("Production".parse(), Ok(Environment::Production)));
+        assert!(matches!(
+            "staging".parse::<Environment>(),
+            Err(EnvError::InvalidEnvironment(_))
+        ));
+    }
+
+    #[test]
+    fn test_serde_environment() {
+        assert_eq!(
+            serde_json::to_value(Environment::Sandbox).unwrap(),
+            serde_json::json!("sandbox")
+        );
+        assert!(matches!(
+            serde_json::from_value(serde_json::json!("Production")),
+            Ok(Environment::Production)
+        ));
+        assert!(serde_json::from_value::<Environment>(serde_json::json!("staging")).is_err());
+    }
+
+    #[cfg(test)]
+    fn rate_limit_error() -> serde_json::Value {
+        serde_json::json!({
+            "request_id": "HNTDNrA8F1shFEW",
+            "error_type": "RATE_LIMIT_EXCEEDED",
+            "error_code": "ACCOUNTS_LIMIT",
+            "error_message": "rate limit exceeded for attempts to access this item. please try again later",
+            "display_message": null
+        })
+    }
+
+    #[tokio::test]
+    async fn test_retry_rate_limited() {
+        let (client, transport) = get_mock_client();
+        let client = client.with_retry_policy(2, Duration::from_millis(1));
+        transport.push_json(
+            "item/remove",
+            reqwest::StatusCode::TOO_MANY_REQUESTS,
+            rate_limit_error(),
+        );
+        transport.push_response(
+            "item/remove",
+            crate::transport::TransportResponse {
+                status: reqwest::StatusCode::TOO_MANY_REQUESTS,
+                headers: HeaderMap::new(),
+                body: b"<html>Too Many Requests</html>".to_vec(),
+            },
+        );
+        transport.push_json(
+            "item/remove",
+            reqwest::StatusCode::OK,
+            serde_json::json!({"request_id": "m8MDnv9okwxFNBV"}),
+        );
+
+        let resp: serde_json::Value = client
+            .send_request("item/remove", &serde_json::json!({}))
+            .await
+            .unwrap();
+        assert_eq!(resp["request_id"], "m8MDnv9okwxFNBV");
+        assert_eq!(transport.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_retry_gives_up() {
+        let (client, transport) = get_mock_client();
+        let client = client.with_retry_policy(2, Duration::from_millis(1));
+        for _ in 0..3 {
+            transport.push_json(
+                "item/remove",
+                reqwest::StatusCode::TOO_MANY_REQUESTS,
+                rate_limit_error(),
+            );
+        }
+
+        let err = client
+            .send_request::<_, serde_json::Value>("item/remove", &serde_json::json!({}))
+            .await
+            .unwrap_err();
+        assert!(err.is_rate_limited());
+        assert!(matches!(err, Error::Plaid(_)));
+        assert_eq!(transport.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_retry_ignores_other_errors() {
+        let (client, transport) = get_mock_client();
+        let client = client.with_retry_policy(2, Duration::from_millis(1));
+        transport.push_json(
+            "item/remove",
+            reqwest::StatusCode::BAD_REQUEST,
+            serde_json::json!({
+                "request_id": "HNTDNrA8F1shFEW",
+                "error_type": "INVALID_INPUT",
+                "error_code": "INVALID_ACCESS_TOKEN",
+                "error_message": "provided access token is in an invalid format",
+                "display_message": null
+            }),
+        );
+
+        let err = client
+            .send_request::<_, serde_json::Value>("item/remove", &serde_json::json!({}))
+            .await
+            .unwrap_err();
+        assert!(!err.is_rate_limited());
+        assert_eq!(transport.requests().len(), 1);
+    }
+
+    #[test]
+    fn test_retry_policy_delay() {
+        let policy = RetryPolicy {
+            max_retries: 3,
+            base_delay: Duration::from_millis(100),
+        };
+        assert_eq!(policy.delay_for(0), Duration::from_millis(100));
+        assert_eq!(policy.delay_for(1), Duration::from_millis(200));
+        assert_eq!(policy.delay_for(3), Duration::from_millis(800));
+        assert_eq!(policy.delay_for(40), Duration::MAX);
+    }
+
+    #[tokio::test]
+    async fn test_with_timeout() {
+        let err = with_timeout(Duration::from_millis(10), async {
+            tokio::time::sleep(Duration::from_secs(5)).await;
+            Ok(())
+        })
+        .await
+        .unwrap_err();
+        assert!(matches!(err, Error::Timeout(timeout) if timeout == Duration::from_millis(10)));
+        assert!(err.is_timeout());
+
+        let (client, transport) = get_mock_client();
+        transport.push_json(
+            "item/remove",
+            reqwest::StatusCode::OK,
+            serde_json::json!({"request_id": "m8MDnv9okwxFNBV"}),
+        );
+        let resp: serde_json::Value = client
+            .send_request_with_timeout(
+                "item/remove",
+                &serde_json::json!({"access_token": "access-sandbox-123"}),
+                Duration::from_secs(5),
+            )
+            .await
+            .unwrap();
+        assert_eq!(resp["request_id"], "m8MDnv9okwxFNBV");
+    }
+
+    #[tokio::test]
+    async fn test_empty_response() {
+        let (client, transport) = get_mock_client();
+        for status in [reqwest::StatusCode::NO_CONTENT, reqwest::StatusCode::OK] {
+            for _ in 0..2 {
+                transport.push_response(
+                    "item/remove",
+                    crate::transport::TransportResponse {
+                        status,
+                        headers: HeaderMap::new(),
+                        body: Vec::new(),
+                    },
+                );
+            }
+        }
+        let req = serde_json::json!({"access_token": "access-sandbox-123"});
+
+        let _: () = client.send_request("item/remove", &req).await.unwrap();
+        let resp: EmptyResponse = client.send_request("item/remove", &req).await.unwrap();
+        assert!(resp.request_id.is_none());
+        let _: () = client.send_request("item/remove", &req).await.unwrap();
+        let err = client
+            .send_request::<_, crate::item::RemoveItemResponse>("item/remove", &req)
+            .await
+            .unwrap_err();
+        assert!(err.is_decode());
+    }
+
+    #[tokio::test]
+    async fn test_send_request_idempotent() {
+        let (client, transport) = get_mock_client();
+        transport.push_json(
+            "transfer/create",
+            reqwest::StatusCode::OK,
+            serde_json::json!({"request_id": "m8MDnv9okwxFNBV"}),
+        );
+        let _: serde_json::Value = client
+            .send_request_idempotent(
+                "transfer/create",
+                &serde_json::json!({}),
+                "2d5f6c87-8a8e-4b8e-9c43-0f0a3c1e9a11",
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            transport.requests()[0].headers["Plaid-Idempotency-Key"],
+            "2d5f6c87-8a8e-4b8e-9c43-0f0a3c1e9a11"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_api_version_header() {
+        let (client, transport) = get_mock_client();
+        for _ in 0..2 {
+            transport.push_json(
+                "item/remove",
+                reqwest::StatusCode::OK,
+                serde_json::json!({"request_id": "m8MDnv9okwxFNBV"}),
+            );
+        }
+        let req = serde_json::json!({"access_token": "access-sandbox-123"});
+
+        let _: serde_json::Value = client.send_request("item/remove", &req).await.unwrap();
+        let _: serde_json::Value = client
+            .with_api_version("2020-09-14")
+            .send_request("item/remove", &req)
+            .await
+            .unwrap();
+
+        let requests = transport.requests();
+        assert!(requests[0].headers.get("Plaid-Version").is_none());
+        assert_eq!(requests[1].headers["Plaid-Version"], "2020-09-14");
+    }
+
+    #[tokio::test]
+    async fn test_send_request_with_meta() {
+        let client = get_test_client();
+        let (resp, meta): (crate::categories::GetCategoriesResponse, ResponseMeta) = client
+            .send_request_with_meta("categories/get", &serde_json::json!({}))
+            .await
+            .unwrap();
+        assert!(!resp.categories.is_empty());
+        assert_eq!(meta.status, reqwest::StatusCode::OK);
+        assert!(meta
+            .header("content-type")
+            .unwrap()
+            .starts_with("application/json"));
+    }
 }
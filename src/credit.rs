@@ -0,0 +1,259 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::client::Client;
+use crate::errors::Result;
+use crate::types::Money;
+
+#[derive(Serialize)]
+struct GetBankIncomeRequest<'a> {
+    client_id: &'a str,
+    secret: &'a str,
+    user_token: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    options: Option<GetBankIncomeOptions>,
+}
+
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct GetBankIncomeOptions {
+    /// How many Bank Income Reports should be fetched. Multiple reports may be available if the report has been re-created or refreshed. If more than one report is available, the most recent reports will be returned first. The default value is 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<i32>,
+}
+
+string_enum! {
+    /// The income category of a bank income source.
+    pub enum BankIncomeCategory {
+        /// Regular salary or wages from an employer.
+        Salary => "SALARY",
+        /// Unemployment benefits.
+        Unemployment => "UNEMPLOYMENT",
+        /// Cash income.
+        Cash => "CASH",
+        /// Income from gig economy work, e.g. ride sharing or deliveries.
+        GigEconomy => "GIG_ECONOMY",
+        /// Rental income.
+        Rental => "RENTAL",
+        /// Child support payments.
+        ChildSupport => "CHILD_SUPPORT",
+        /// Military pay.
+        Military => "MILITARY",
+        /// Retirement income, e.g. a pension or social security.
+        Retirement => "RETIREMENT",
+        /// Long-term disability benefits.
+        LongTermDisability => "LONG_TERM_DISABILITY",
+        /// Interest paid by a bank.
+        BankInterest => "BANK_INTEREST",
+        /// Cash deposited into the account.
+        CashDeposit => "CASH_DEPOSIT",
+        /// Transfers from a payment application, e.g. Venmo.
+        TransferFromApplication => "TRANSFER_FROM_APPLICATION",
+        /// A tax refund.
+        TaxRefund => "TAX_REFUND",
+        /// Other government benefits.
+        BenefitOther => "BENEFIT_OTHER",
+        /// Income that does not fit any other category.
+        Other => "OTHER",
+        _ => Unknown,
+    }
+}
+
+string_enum! {
+    /// How often a bank income source pays out.
+    pub enum BankIncomePayFrequency {
+        /// Paid every week.
+        Weekly => "WEEKLY",
+        /// Paid every two weeks.
+        Biweekly => "BIWEEKLY",
+        /// Paid twice a month.
+        SemiMonthly => "SEMI_MONTHLY",
+        /// Paid every month.
+        Monthly => "MONTHLY",
+        /// Paid every day.
+        Daily => "DAILY",
+        /// Plaid could not determine the pay frequency.
+        Unknown => "UNKNOWN",
+        _ => Other,
+    }
+}
+
+/// An income source detected from the transactions of an account.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BankIncomeSource {
+    /// A unique identifier for an income source.
+    pub income_source_id: String,
+    /// The most common name or original description for the underlying income transactions.
+    pub income_description: String,
+    /// The income category.
+    pub income_category: BankIncomeCategory,
+    /// Plaid's unique identifier for the account.
+    pub account_id: String,
+    /// Minimum of all dates within the specific income sources in the user's bank account for days requested by the client.
+    pub start_date: Option<NaiveDate>,
+    /// Maximum of all dates within the specific income sources in the user’s bank account for days requested by the client.
+    pub end_date: Option<NaiveDate>,
+    /// The expected pay frequency.
+    pub pay_frequency: BankIncomePayFrequency,
+    /// Total amount of earnings in the user’s bank account for the specific income source for days requested by the client.
+    pub total_amount: Money,
+    /// Number of transactions for the income source within the start and end date.
+    pub transaction_count: i32,
+}
+
+/// The income sources detected on an Item.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BankIncomeItem {
+    /// The Plaid Item ID.
+    pub item_id: String,
+    /// The unique identifier of the institution associated with the Item.
+    pub institution_id: Option<String>,
+    /// The name of the institution associated with the Item.
+    pub institution_name: Option<String>,
+    /// The time when this Item's data was last retrieved from the financial institution.
+    pub last_updated_time: Option<DateTime<Utc>>,
+    /// The income sources for this Item. Each entry in the array is a single income source.
+    #[serde(default)]
+    pub bank_income_sources: Vec<BankIncomeSource>,
+}
+
+/// A summary of the income across every Item of a report.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BankIncomeSummary {
+    /// Total amount of earnings across all the income sources in the end user's Items for the days requested by the client.
+    pub total_amount: Option<Money>,
+    /// The ISO 4217 currency code of the amount.
+    pub iso_currency_code: Option<String>,
+    /// The unofficial currency code of the amount. Always null if iso_currency_code is non-null.
+    pub unofficial_currency_code: Option<String>,
+    /// The earliest date within the days requested in which all income sources identified by Plaid appear in a user's account.
+    pub start_date: Option<NaiveDate>,
+    /// The latest date in which all income sources identified by Plaid appear in the user's account.
+    pub end_date: Option<NaiveDate>,
+    /// Number of income sources per end user.
+    pub income_sources_count: Option<i32>,
+    /// Number of income categories per end user.
+    pub income_categories_count: Option<i32>,
+    /// Number of income transactions per end user.
+    pub income_transactions_count: Option<i32>,
+}
+
+/// A Bank Income Report.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BankIncome {
+    /// The unique identifier associated with the Bank Income Report.
+    pub bank_income_id: String,
+    /// The time when the report was generated.
+    pub generated_time: Option<DateTime<Utc>>,
+    /// The number of days requested by the customer for the report.
+    pub days_requested: Option<i32>,
+    /// The Items the report covers.
+    #[serde(default)]
+    pub items: Vec<BankIncomeItem>,
+    /// Summary for bank income across all income sources and Items.
+    pub bank_income_summary: Option<BankIncomeSummary>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GetBankIncomeResponse {
+    /// A unique identifier for the request, which can be used for troubleshooting. This identifier, like all Plaid identifiers, is case sensitive.
+    pub request_id: String,
+    /// The Bank Income Reports of the user, most recent first.
+    #[serde(default)]
+    pub bank_income: Vec<BankIncome>,
+}
+
+impl Client {
+    /// Retrieve information from the bank accounts used for income verification.
+    ///
+    /// /credit/bank_income/get returns the bank income report(s) for a specified user, summarizing the income sources detected in the transactions of their linked accounts.
+    ///
+    /// * `user_token` - The user token associated with the User data is being requested for.
+    /// * `options` - An optional object for /credit/bank_income/get request options.
+    pub async fn get_bank_income(
+        &self,
+        user_token: &str,
+        options: Option<GetBankIncomeOptions>,
+    ) -> Result<GetBankIncomeResponse> {
+        self.send_request(
+            "credit/bank_income/get",
+            &GetBankIncomeRequest {
+                client_id: self.client_id(),
+                secret: self.secret(),
+                user_token,
+                options,
+            },
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::tests::get_mock_client;
+    use reqwest::StatusCode;
+
+    #[tokio::test]
+    async fn test_get_bank_income() {
+        let (client, transport) = get_mock_client();
+        transport.push_json(
+            "credit/bank_income/get",
+            StatusCode::OK,
+            serde_json::json!({
+                "request_id": "LhQf0THi8SH1yJm",
+                "bank_income": [{
+                    "bank_income_id": "dacc92a0-2b5d-4e3b-9f7b-6f0b1d4a3e21",
+                    "generated_time": "2022-01-31T22:47:53Z",
+                    "days_requested": 90,
+                    "items": [{
+                        "item_id": "AZMP7JrGXgtPd3AQMeg7hwMKgk5E8qU1V5ME7",
+                        "institution_id": "ins_0",
+                        "institution_name": "Plaid Bank",
+                        "last_updated_time": "2022-01-31T22:47:53Z",
+                        "bank_income_sources": [{
+                            "income_source_id": "f17efbdd-caab-4278-8ece-963511cd3d51",
+                            "income_description": "PLAID_INC_DIRECT_DEP_PPD",
+                            "income_category": "SALARY",
+                            "account_id": "1qKRXQjk8xUWDJojNwPXTj8gEmR48piqRNye8",
+                            "start_date": "2021-11-15",
+                            "end_date": "2022-01-15",
+                            "pay_frequency": "MONTHLY",
+                            "total_amount": 300,
+                            "transaction_count": 1
+                        }]
+                    }],
+                    "bank_income_summary": {
+                        "total_amount": 300,
+                        "iso_currency_code": "USD",
+                        "unofficial_currency_code": null,
+                        "start_date": "2021-11-15",
+                        "end_date": "2022-01-15",
+                        "income_sources_count": 1,
+                        "income_categories_count": 1,
+                        "income_transactions_count": 1
+                    }
+                }]
+            }),
+        );
+
+        let resp = client
+            .get_bank_income(
+                "user-sandbox-b0e2c4ee-a763-4df5-bfe9-46a46bce993d",
+                Some(GetBankIncomeOptions { count: Some(1) }),
+            )
+            .await
+            .unwrap();
+        let source = &resp.bank_income[0].items[0].bank_income_sources[0];
+        assert_eq!(source.income_category, BankIncomeCategory::Salary);
+        assert_eq!(source.pay_frequency, BankIncomePayFrequency::Monthly);
+        assert_eq!(source.total_amount, Money::from(300));
+
+        let body: serde_json::Value =
+            serde_json::from_slice(&transport.requests()[0].body).unwrap();
+        assert_eq!(
+            body["user_token"],
+            "user-sandbox-b0e2c4ee-a763-4df5-bfe9-46a46bce993d"
+        );
+        assert_eq!(body["options"], serde_json::json!({"count": 1}));
+    }
+}
@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::client::Client;
 use crate::errors::Result;
+use crate::types::{CountryCode, Money};
 
 #[derive(Serialize)]
 struct GetDepositSwitchRequest<'a> {
@@ -11,7 +12,7 @@ struct GetDepositSwitchRequest<'a> {
     deposit_switch_id: &'a str,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct GetDepositSwitchResponse {
     /// A unique identifier for the request, which can be used for troubleshooting. This identifier, like all Plaid identifiers, is case sensitive.
     pub request_id: String,
@@ -31,7 +32,7 @@ pub struct GetDepositSwitchResponse {
     /// The percentage of direct deposit allocated to the target account. Always null if the target account is not allocated a percentage or if the deposit switch has not been completed or if is_allocated_remainder is true.
     pub percent_allocated: Option<i32>,
     /// The dollar amount of direct deposit allocated to the target account. Always null if the target account is not allocated an amount or if the deposit switch has not been completed.
-    pub amount_allocated: Option<f64>,
+    pub amount_allocated: Option<Money>,
     /// ISO8601 date the deposit switch was created.
     pub date_created: NaiveDate,
     /// ISO8601 date the deposit switch was completed. Always null if the deposit switch has not been completed.
@@ -44,9 +45,79 @@ struct CreateDepositSwitchRequest<'a> {
     secret: &'a str,
     target_access_token: &'a str,
     target_account_id: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    country_code: Option<CountryCode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    options: Option<CreateDepositSwitchOptions<'a>>,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct CreateDepositSwitchOptions<'a> {
+    /// The URL registered to receive webhooks when the status of a deposit switch request has changed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webhook: Option<&'a str>,
+    /// An array of access tokens corresponding to transaction items to use when attempting to match the user to their Payroll Provider.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_item_access_tokens: Option<&'a [&'a str]>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct DepositSwitchTargetAccount<'a> {
+    /// Account number for deposit switch destination
+    pub account_number: &'a str,
+    /// Routing number for deposit switch destination
+    pub routing_number: &'a str,
+    /// The name of the deposit switch destination account, as it will be displayed to the end user in the Deposit Switch interface. It is not required to match the name used in online banking.
+    pub account_name: &'a str,
+    /// The account subtype of the account, either checking or savings.
+    pub account_subtype: &'a str,
+}
+
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct DepositSwitchAddress<'a> {
+    /// The full city name
+    pub city: &'a str,
+    /// The region or state
+    pub region: &'a str,
+    /// The full street address
+    pub street: &'a str,
+    /// The postal code
+    pub postal_code: &'a str,
+    /// The ISO 3166-1 alpha-2 country code
+    pub country: &'a str,
+}
+
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct DepositSwitchTargetUser<'a> {
+    /// The given name (first name) of the user.
+    pub given_name: &'a str,
+    /// The family name (last name) of the user.
+    pub family_name: &'a str,
+    /// The phone number of the user. The endpoint can accept a variety of phone number formats, including E.164.
+    pub phone: &'a str,
+    /// The email address of the user.
+    pub email: &'a str,
+    /// The user's address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<DepositSwitchAddress<'a>>,
+    /// The taxpayer ID of the user, generally their SSN, EIN, or TIN.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_payer_id: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+struct CreateDepositSwitchAltRequest<'a> {
+    client_id: &'a str,
+    secret: &'a str,
+    target_account: DepositSwitchTargetAccount<'a>,
+    target_user: DepositSwitchTargetUser<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    country_code: Option<CountryCode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    options: Option<CreateDepositSwitchOptions<'a>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct CreateDepositSwitchResponse {
     /// A unique identifier for the request, which can be used for troubleshooting. This identifier, like all Plaid identifiers, is case sensitive.
     pub request_id: String,
@@ -60,15 +131,15 @@ impl Client {
     /// This endpoint returns information related to how the user has configured their payroll allocation and the state of the switch. You can use this information to build logic related to the user's direct deposit allocation preferences.
     ///
     /// * `deposit_switch_id` - The ID of the deposit switch
-    pub async fn get_deposit_switch<'a>(
+    pub async fn get_deposit_switch(
         &self,
         deposit_switch_id: &str,
     ) -> Result<GetDepositSwitchResponse> {
         self.send_request(
             "deposit_switch/get",
             &GetDepositSwitchRequest {
-                client_id: &self.client_id,
-                secret: &self.secret,
+                client_id: self.client_id(),
+                secret: self.secret(),
                 deposit_switch_id,
             },
         )
@@ -80,21 +151,114 @@ impl Client {
     /// This endpoint creates a deposit switch entity that will be persisted throughout the lifecycle of the switch.
     ///
     /// * `target_account_id` - Plaid Account ID that specifies the target bank account. This account will become the recipient for a user's direct deposit.
-    /// * `target_access_token` - Access token for the target Item, typically provided in the Import Item response.
+    /// * `target_access_token` - Access token for the target Item, typically provided in the Import Item response (see `import_item`).
+    /// * `country_code` - ISO-3166-1 alpha-2 country code standard.
+    /// * `options` - Options to configure the /deposit_switch/create request.
     pub async fn create_deposit_switch<'a>(
         &self,
         target_account_id: &str,
         target_access_token: &str,
+        country_code: Option<CountryCode>,
+        options: Option<CreateDepositSwitchOptions<'a>>,
     ) -> Result<CreateDepositSwitchResponse> {
         self.send_request(
             "deposit_switch/create",
             &CreateDepositSwitchRequest {
-                client_id: &self.client_id,
-                secret: &self.secret,
+                client_id: self.client_id(),
+                secret: self.secret(),
                 target_account_id,
                 target_access_token,
+                country_code,
+                options,
             },
         )
         .await
     }
+
+    /// Create a deposit switch without using Plaid Exchange.
+    ///
+    /// This endpoint provides an alternative to /deposit_switch/create for customers who have not yet fully integrated with Plaid Exchange. Like /deposit_switch/create, it creates a deposit switch entity that will be persisted throughout the lifecycle of the switch, but the target account is given by its account and routing numbers.
+    ///
+    /// * `target_account` - The deposit switch destination account.
+    /// * `target_user` - The deposit switch target user.
+    /// * `country_code` - ISO-3166-1 alpha-2 country code standard.
+    /// * `options` - Options to configure the /deposit_switch/alt/create request.
+    pub async fn create_deposit_switch_alt<'a>(
+        &self,
+        target_account: DepositSwitchTargetAccount<'a>,
+        target_user: DepositSwitchTargetUser<'a>,
+        country_code: Option<CountryCode>,
+        options: Option<CreateDepositSwitchOptions<'a>>,
+    ) -> Result<CreateDepositSwitchResponse> {
+        self.send_request(
+            "deposit_switch/alt/create",
+            &CreateDepositSwitchAltRequest {
+                client_id: self.client_id(),
+                secret: self.secret(),
+                target_account,
+                target_user,
+                country_code,
+                options,
+            },
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::tests::get_mock_client;
+    use reqwest::StatusCode;
+
+    #[tokio::test]
+    async fn test_create_deposit_switch_alt() {
+        let (client, transport) = get_mock_client();
+        transport.push_json(
+            "deposit_switch/alt/create",
+            StatusCode::OK,
+            serde_json::json!({
+                "deposit_switch_id": "c7jMwPPManIwy9rwOewwOoZZlbKy6Ly4b7pqI",
+                "request_id": "lMjeOeu9X1VUh1F"
+            }),
+        );
+
+        let resp = client
+            .create_deposit_switch_alt(
+                DepositSwitchTargetAccount {
+                    account_number: "9900009606",
+                    routing_number: "011401533",
+                    account_name: "Checking Account",
+                    account_subtype: "checking",
+                },
+                DepositSwitchTargetUser {
+                    given_name: "Quinn",
+                    family_name: "Pho",
+                    phone: "+16175555555",
+                    email: "user@example.com",
+                    ..Default::default()
+                },
+                Some(CountryCode::US),
+                Some(CreateDepositSwitchOptions {
+                    transaction_item_access_tokens: Some(&["access-sandbox-123"]),
+                    ..Default::default()
+                }),
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            resp.deposit_switch_id,
+            "c7jMwPPManIwy9rwOewwOoZZlbKy6Ly4b7pqI"
+        );
+
+        let body: serde_json::Value =
+            serde_json::from_slice(&transport.requests()[0].body).unwrap();
+        assert_eq!(body["target_account"]["routing_number"], "011401533");
+        assert_eq!(body["target_user"]["given_name"], "Quinn");
+        assert_eq!(body["country_code"], "US");
+        assert_eq!(
+            body["options"],
+            serde_json::json!({"transaction_item_access_tokens": ["access-sandbox-123"]})
+        );
+    }
 }
@@ -1,9 +1,9 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::fmt;
 
 pub type Result<T> = std::result::Result<T, Error>;
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ErrorResponse {
     pub request_id: String,
     pub error_type: String,
@@ -15,9 +15,22 @@ pub struct ErrorResponse {
 #[derive(Debug)]
 pub enum Error {
     /// Error returned by the Plaid API
-    Plaid(PlaidError),
+    Plaid(Box<PlaidError>),
     /// Error when sending request
     Request(reqwest::Error),
+    /// Error when a webhook could not be verified
+    WebhookVerification(String),
+    /// Error when the request was rejected locally before being sent
+    InvalidRequest(String),
+    /// Error when a response body could not be deserialized
+    Json(serde_json::Error),
+    /// Error when a non-success response body is not a Plaid error, e.g. an HTML page from a proxy
+    UnexpectedResponse {
+        status: reqwest::StatusCode,
+        body: String,
+    },
+    /// Error when a call did not complete within the deadline given to `client::with_timeout`
+    Timeout(std::time::Duration),
 }
 
 impl fmt::Display for Error {
@@ -28,14 +41,62 @@ impl fmt::Display for Error {
             match self {
                 Self::Plaid(err) => err.to_string(),
                 Self::Request(err) => err.to_string(),
+                Self::WebhookVerification(msg) => format!("Webhook verification failed - {}", msg),
+                Self::InvalidRequest(msg) => format!("Invalid request - {}", msg),
+                Self::Json(err) => err.to_string(),
+                Self::UnexpectedResponse { status, body } =>
+                    format!("Unexpected response - {}: {}", status, body),
+                Self::Timeout(timeout) => format!("Timed out after {:?}", timeout),
             },
         )
     }
 }
 
+impl Error {
+    /// Returns true if the request was rejected because a rate limit was exceeded, including HTTP 429 responses whose body is not a Plaid error.
+    pub fn is_rate_limited(&self) -> bool {
+        match self {
+            Self::Plaid(err) => err.is_rate_limited(),
+            Self::UnexpectedResponse { status, .. } => {
+                *status == reqwest::StatusCode::TOO_MANY_REQUESTS
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns true if the request timed out, either in the HTTP client or because a per-call deadline elapsed.
+    pub fn is_timeout(&self) -> bool {
+        match self {
+            Self::Request(err) => err.is_timeout(),
+            Self::Timeout(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Returns true if the connection to Plaid could not be established.
+    pub fn is_connect(&self) -> bool {
+        matches!(self, Self::Request(err) if err.is_connect())
+    }
+
+    /// Returns true if the response body could not be decoded.
+    pub fn is_decode(&self) -> bool {
+        match self {
+            Self::Request(err) => err.is_decode(),
+            Self::Json(_) => true,
+            _ => false,
+        }
+    }
+}
+
 impl From<PlaidError> for Error {
     fn from(err: PlaidError) -> Self {
-        Self::Plaid(err)
+        Self::Plaid(Box::new(err))
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        Self::Json(err)
     }
 }
 
@@ -45,7 +106,105 @@ impl From<reqwest::Error> for Error {
     }
 }
 
-#[derive(Debug, Clone)]
+/// Error when creating a client from environment variables
+#[derive(Debug, Clone, PartialEq)]
+pub enum EnvError {
+    /// A required environment variable is not set
+    Missing(&'static str),
+    /// PLAID_ENVIRONMENT is not one of SANDBOX, DEVELOPMENT or PRODUCTION
+    InvalidEnvironment(String),
+}
+
+impl fmt::Display for EnvError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Missing(name) => write!(f, "Missing environment variable: {}", name),
+            Self::InvalidEnvironment(value) => write!(
+                f,
+                "Environment variable PLAID_ENVIRONMENT must be set to SANDBOX, DEVELOPMENT or PRODUCTION. Is actually {}",
+                value
+            ),
+        }
+    }
+}
+
+string_enum! {
+    /// The particular error code of a Plaid error.
+    pub enum PlaidErrorCode {
+        /// The financial institution indicated that the user's password or MFA information has changed.
+        ItemLoginRequired => "ITEM_LOGIN_REQUIRED",
+        /// The financial institution indicated that the user's account is locked.
+        ItemLocked => "ITEM_LOCKED",
+        /// Plaid is unable to support this user's accounts.
+        ItemNotSupported => "ITEM_NOT_SUPPORTED",
+        /// The Item you requested cannot be found.
+        ItemNotFound => "ITEM_NOT_FOUND",
+        /// The financial institution indicated that the credentials provided were invalid.
+        InvalidCredentials => "INVALID_CREDENTIALS",
+        /// The financial institution indicated that the MFA response provided was invalid.
+        InvalidMfa => "INVALID_MFA",
+        /// The user did not provide sufficient authorization to link their account.
+        InsufficientCredentials => "INSUFFICIENT_CREDENTIALS",
+        /// The user must log into their financial institution to complete an action.
+        UserSetupRequired => "USER_SETUP_REQUIRED",
+        /// The type of MFA required by the institution is not supported.
+        MfaNotSupported => "MFA_NOT_SUPPORTED",
+        /// No compatible accounts could be found.
+        NoAccounts => "NO_ACCOUNTS",
+        /// No valid checking or savings accounts were found for Auth.
+        NoAuthAccounts => "NO_AUTH_ACCOUNTS",
+        /// No investment accounts were found.
+        NoInvestmentAccounts => "NO_INVESTMENT_ACCOUNTS",
+        /// No liability accounts were found.
+        NoLiabilityAccounts => "NO_LIABILITY_ACCOUNTS",
+        /// The requested product is not yet ready. Retry the request later.
+        ProductNotReady => "PRODUCT_NOT_READY",
+        /// The requested product is not supported by the Item's institution.
+        ProductsNotSupported => "PRODUCTS_NOT_SUPPORTED",
+        /// The user did not grant access to the data required by the product.
+        AccessNotGranted => "ACCESS_NOT_GRANTED",
+        /// The access_token provided is invalid.
+        InvalidAccessToken => "INVALID_ACCESS_TOKEN",
+        /// The public_token provided is invalid or expired.
+        InvalidPublicToken => "INVALID_PUBLIC_TOKEN",
+        /// The client_id or secret provided is invalid.
+        InvalidApiKeys => "INVALID_API_KEYS",
+        /// The client is not authorized to access the requested product.
+        InvalidProduct => "INVALID_PRODUCT",
+        /// One or more of the request fields is invalid.
+        InvalidField => "INVALID_FIELD",
+        /// The request is missing one or more required fields.
+        MissingFields => "MISSING_FIELDS",
+        /// The client is not authorized to access this environment.
+        UnauthorizedEnvironment => "UNAUTHORIZED_ENVIRONMENT",
+        /// The requested product is not enabled for the Sandbox Item.
+        SandboxProductNotEnabled => "SANDBOX_PRODUCT_NOT_ENABLED",
+        /// The financial institution is down.
+        InstitutionDown => "INSTITUTION_DOWN",
+        /// The financial institution is failing to respond to requests.
+        InstitutionNotResponding => "INSTITUTION_NOT_RESPONDING",
+        /// The financial institution is not available.
+        InstitutionNotAvailable => "INSTITUTION_NOT_AVAILABLE",
+        /// Plaid no longer supports this financial institution.
+        InstitutionNoLongerSupported => "INSTITUTION_NO_LONGER_SUPPORTED",
+        /// The requested institution was not found.
+        InstitutionNotFound => "INSTITUTION_NOT_FOUND",
+        /// Too many requests were made.
+        RateLimit => "RATE_LIMIT",
+        /// Transactions changed while paginating through /transactions/sync. Restart from the first cursor of the update.
+        TransactionsSyncMutationDuringPagination => "TRANSACTIONS_SYNC_MUTATION_DURING_PAGINATION",
+        /// Plaid was unable to process the request.
+        InternalServerError => "INTERNAL_SERVER_ERROR",
+        /// Plaid is undergoing planned maintenance.
+        PlannedMaintenance => "PLANNED_MAINTENANCE",
+        _ => Unknown,
+    }
+}
+
+/// An error returned by the Plaid API.
+///
+/// Serializes to a JSON object with the same fields as a Plaid error response, plus status_code as a number, so it can be logged as a single structured field.
+#[derive(Serialize, Debug, Clone)]
 pub struct PlaidError {
     /// A broad categorization of the error. Safe for programatic use.
     /// Possible values: INVALID_REQUEST, INVALID_INPUT, INSTITUTION_ERROR, RATE_LIMIT_EXCEEDED, API_ERROR, ITEM_ERROR, ASSET_REPORT_ERROR, RECAPTCHA_ERROR, OAUTH_ERROR, PAYMENT_ERROR, BANK_TRANSFER_ERROR
@@ -60,9 +219,58 @@ pub struct PlaidError {
     /// A unique identifying the request, to be used for troubleshooting purposes. This field will be omitted in errors provided by webhooks.
     pub request_id: String,
     /// The HTTP status code associated with the error.
+    #[serde(serialize_with = "serialize_status_code")]
     pub status_code: reqwest::StatusCode,
 }
 
+fn serialize_status_code<S>(
+    status_code: &reqwest::StatusCode,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.serialize_u16(status_code.as_u16())
+}
+
+impl PlaidError {
+    /// Returns the typed error code.
+    pub fn code(&self) -> PlaidErrorCode {
+        PlaidErrorCode::from(&self.error_code[..])
+    }
+
+    /// Returns true if the request was rejected because a rate limit was exceeded.
+    pub fn is_rate_limited(&self) -> bool {
+        self.error_type == "RATE_LIMIT_EXCEEDED"
+            || self.status_code == reqwest::StatusCode::TOO_MANY_REQUESTS
+    }
+}
+
+/// An error embedded in an otherwise successful response, such as the error on an Item.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ItemError {
+    /// A broad categorization of the error. Safe for programatic use.
+    pub error_type: String,
+    /// The particular error code. Safe for programmatic use.
+    pub error_code: String,
+    /// A developer-friendly representation of the error code. This may change over time and is not safe for programmatic use.
+    pub error_message: String,
+    /// A user-friendly representation of the error code. None if the error is not related to user action.
+    /// This may change over time and is not safe for programmatic use.
+    pub display_message: Option<String>,
+    /// A unique identifying the request, to be used for troubleshooting purposes. Usually omitted for errors embedded in a response.
+    pub request_id: Option<String>,
+    /// The HTTP status code associated with the error. This will only be returned in the response body when the error information is provided via a webhook.
+    pub status: Option<u16>,
+}
+
+impl ItemError {
+    /// Returns the typed error code.
+    pub fn code(&self) -> PlaidErrorCode {
+        PlaidErrorCode::from(&self.error_code[..])
+    }
+}
+
 impl fmt::Display for PlaidError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
@@ -77,3 +285,75 @@ impl fmt::Display for PlaidError {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plaid_error_code() {
+        let err = PlaidError {
+            error_type: "ITEM_ERROR".to_string(),
+            error_code: "ITEM_LOGIN_REQUIRED".to_string(),
+            error_message: "the login details of this item have changed".to_string(),
+            display_message: None,
+            request_id: "request_id".to_string(),
+            status_code: reqwest::StatusCode::BAD_REQUEST,
+        };
+        assert_eq!(err.code(), PlaidErrorCode::ItemLoginRequired);
+
+        let code = PlaidErrorCode::from("SOME_NEW_CODE");
+        assert_eq!(code, PlaidErrorCode::Unknown("SOME_NEW_CODE".to_string()));
+        assert_eq!(code.as_str(), "SOME_NEW_CODE");
+    }
+
+    #[test]
+    fn test_serialize_plaid_error() {
+        let err = PlaidError {
+            error_type: "RATE_LIMIT_EXCEEDED".to_string(),
+            error_code: "ACCOUNTS_LIMIT".to_string(),
+            error_message: "rate limit exceeded for attempts to access this item".to_string(),
+            display_message: None,
+            request_id: "HNTDNrA8F1shFEW".to_string(),
+            status_code: reqwest::StatusCode::TOO_MANY_REQUESTS,
+        };
+        assert_eq!(
+            serde_json::to_value(&err).unwrap(),
+            serde_json::json!({
+                "error_type": "RATE_LIMIT_EXCEEDED",
+                "error_code": "ACCOUNTS_LIMIT",
+                "error_message": "rate limit exceeded for attempts to access this item",
+                "display_message": null,
+                "request_id": "HNTDNrA8F1shFEW",
+                "status_code": 429
+            })
+        );
+    }
+
+    #[test]
+    fn test_item_error_code() {
+        let err: ItemError = serde_json::from_value(serde_json::json!({
+            "error_type": "ITEM_ERROR",
+            "error_code": "ITEM_LOGIN_REQUIRED",
+            "error_message": "the login details of this item have changed (credentials, MFA, or required user action) and a user login is required to update this information",
+            "display_message": null
+        }))
+        .unwrap();
+        assert_eq!(err.code(), PlaidErrorCode::ItemLoginRequired);
+        assert!(err.request_id.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_error_kind() {
+        let err: Error = reqwest::get("http://127.0.0.1:1").await.unwrap_err().into();
+        assert!(err.is_connect());
+        assert!(!err.is_timeout());
+        assert!(!err.is_decode());
+
+        let err: Error = serde_json::from_str::<ErrorResponse>("<html>")
+            .unwrap_err()
+            .into();
+        assert!(err.is_decode());
+        assert!(!err.is_connect());
+    }
+}
@@ -0,0 +1,57 @@
+//! JSON fixtures shared by the unit tests of several modules.
+
+use serde_json::Value;
+
+/// Recursively overwrites the fields of `base` with those of `patch`, leaving fields missing from `patch` untouched.
+pub(crate) fn merge(mut base: Value, patch: Value) -> Value {
+    match (&mut base, patch) {
+        (Value::Object(base_fields), Value::Object(patch_fields)) => {
+            for (key, value) in patch_fields {
+                let field = base_fields.remove(&key).unwrap_or(Value::Null);
+                base_fields.insert(key, merge(field, value));
+            }
+            base
+        }
+        (_, patch) => patch,
+    }
+}
+
+/// An account as returned by /accounts/get, with USD balances of 100 available and 110 current.
+pub(crate) fn account_fixture(
+    account_id: &str,
+    account_type: &str,
+    subtype: Option<&str>,
+) -> Value {
+    serde_json::json!({
+        "account_id": account_id,
+        "balances": {"available": 100, "current": 110, "limit": null, "iso_currency_code": "USD", "unofficial_currency_code": null},
+        "mask": "0000",
+        "name": "Plaid Account",
+        "official_name": null,
+        "type": account_type,
+        "subtype": subtype
+    })
+}
+
+/// A security as returned by /investments/holdings/get, with only the required fields set.
+pub(crate) fn security_fixture(security_id: &str) -> Value {
+    serde_json::json!({
+        "security_id": security_id,
+        "is_cash_equivalent": false
+    })
+}
+
+/// A holding as returned by /investments/holdings/get, priced at 1 so that its quantity equals `institution_value`.
+pub(crate) fn holding_fixture(
+    account_id: &str,
+    security_id: &str,
+    institution_value: i32,
+) -> Value {
+    serde_json::json!({
+        "account_id": account_id,
+        "security_id": security_id,
+        "institution_price": 1,
+        "institution_value": institution_value,
+        "quantity": institution_value
+    })
+}
@@ -1,12 +1,14 @@
-use chrono::NaiveDate;
+use chrono::{NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 use crate::accounts::Account;
 use crate::client::Client;
 use crate::errors::Result;
 use crate::item::Item;
+use crate::types::Money;
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Security {
     /// A unique, Plaid-specific identifier for the security, used to associate securities with holdings. Like all Plaid identifiers, the security_id is case sensitive.
     pub security_id: String,
@@ -31,7 +33,7 @@ pub struct Security {
     /// The security type of the holding.
     pub r#type: Option<String>,
     /// Price of the security at the close of the previous trading session. null for non-public securities.
-    pub close_price: Option<f64>,
+    pub close_price: Option<Money>,
     /// Date for which close_price is accurate. Always null if close_price is null.
     pub close_price_as_of: Option<NaiveDate>,
     /// The ISO-4217 currency code of the price given. Always null if unofficial_currency_code is non-null.
@@ -40,20 +42,36 @@ pub struct Security {
     pub unofficial_currency_code: Option<String>,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+impl Security {
+    /// Returns true if the security has a close_price that is more than `max_age_days` days older than `as_of`, or whose date is unknown. Securities without a close_price are never stale.
+    ///
+    /// * `as_of` - The date to measure the age of the price against, usually today.
+    /// * `max_age_days` - The maximum age of the price, in days.
+    pub fn price_is_stale(&self, as_of: NaiveDate, max_age_days: i64) -> bool {
+        if self.close_price.is_none() {
+            return false;
+        }
+        match self.close_price_as_of {
+            Some(close_price_as_of) => (as_of - close_price_as_of).num_days() > max_age_days,
+            None => true,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Holding {
     /// The Plaid account_id associated with the holding.
     pub account_id: String,
     /// The Plaid security_id associated with the holding.
     pub security_id: String,
     /// The last price given by the institution for this security.
-    pub institution_price: f64,
+    pub institution_price: Money,
     /// The date at which institution_price was current.
     pub institution_price_as_of: Option<NaiveDate>,
     /// The value of the holding, as reported by the institution.
-    pub institution_value: f64,
+    pub institution_value: Money,
     /// The cost basis of the holding.
-    pub cost_basis: Option<f64>,
+    pub cost_basis: Option<Money>,
     /// The total quantity of the asset held, as reported by the financial institution.
     pub quantity: f64,
     /// The ISO-4217 currency code of the holding. Always null if unofficial_currency_code is non-null.
@@ -77,7 +95,7 @@ pub struct GetHoldingsOptions<'a> {
     pub account_ids: Option<&'a [&'a str]>,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct GetHoldingsResponse {
     /// A unique identifier for the request, which can be used for troubleshooting. This identifier, like all Plaid identifiers, is case sensitive.
     pub request_id: String,
@@ -91,6 +109,179 @@ pub struct GetHoldingsResponse {
     pub item: Item,
 }
 
+impl GetHoldingsResponse {
+    /// Pairs each holding with its security.
+    ///
+    /// When a holding's security has no close_price but names a proxy_security_id that is present in the response, the proxy security is returned instead. The security is None if it is missing from the response.
+    pub fn holdings_with_securities(&self) -> Vec<(&Holding, Option<&Security>)> {
+        let securities: HashMap<&str, &Security> = self
+            .securities
+            .iter()
+            .map(|security| (&security.security_id[..], security))
+            .collect();
+        self.holdings
+            .iter()
+            .map(|holding| {
+                let security = securities
+                    .get(&holding.security_id[..])
+                    .copied()
+                    .map(|security| match &security.proxy_security_id {
+                        Some(proxy_id) if security.close_price.is_none() => {
+                            securities.get(&proxy_id[..]).copied().unwrap_or(security)
+                        }
+                        _ => security,
+                    });
+                (holding, security)
+            })
+            .collect()
+    }
+
+    /// Returns the total value of the holdings in each account, summing institution_value by account_id.
+    ///
+    /// Values are summed as reported, so an account with holdings in more than one currency yields a mixed total.
+    pub fn value_by_account(&self) -> HashMap<String, Money> {
+        let mut values = HashMap::new();
+        for holding in &self.holdings {
+            *values
+                .entry(holding.account_id.clone())
+                .or_insert_with(Money::default) += holding.institution_value;
+        }
+        values
+    }
+
+    /// Returns the securities whose close_price is more than `max_age_days` days old as of today (UTC). See `Security::price_is_stale`.
+    ///
+    /// * `max_age_days` - The maximum age of the price, in days.
+    pub fn stale_securities(&self, max_age_days: i64) -> Vec<&Security> {
+        let today = Utc::now().naive_utc().date();
+        self.securities
+            .iter()
+            .filter(|security| security.price_is_stale(today, max_age_days))
+            .collect()
+    }
+}
+
+/// A holding in an account of a [`Portfolio`], with its security resolved.
+#[derive(Serialize, Debug, Clone)]
+pub struct Position {
+    /// The Plaid security_id of the position.
+    pub security_id: String,
+    /// The name of the security, if the security is present in the response.
+    pub name: Option<String>,
+    /// The trading symbol of the security, if the security is present in the response.
+    pub ticker_symbol: Option<String>,
+    /// The total quantity held, as reported by the financial institution.
+    pub quantity: f64,
+    /// The value of the position, as reported by the institution.
+    pub institution_value: Money,
+    /// The share of the account's total value held in this position, between 0 and 1. None if the account's total value is not positive.
+    pub weight: Option<Money>,
+}
+
+/// An account of a [`Portfolio`] with its positions.
+#[derive(Serialize, Debug, Clone)]
+pub struct PortfolioAccount {
+    /// The Plaid account_id.
+    pub account_id: String,
+    /// The name of the account, if the account is present in the response.
+    pub name: Option<String>,
+    /// The total institution_value of the positions in the account, including cash.
+    pub total_value: Money,
+    /// The total institution_value of the cash-equivalent positions in the account.
+    pub cash_value: Money,
+    /// The positions in securities that are cash equivalents, e.g. money market funds or uninvested cash.
+    pub cash: Vec<Position>,
+    /// The positions in all other securities.
+    pub positions: Vec<Position>,
+}
+
+/// The holdings of an Item grouped by account, as returned by `Portfolio::from_response`.
+#[derive(Serialize, Debug, Clone)]
+pub struct Portfolio {
+    /// The accounts that have holdings, in the order they appear in the response. Accounts that are missing from the response come last.
+    pub accounts: Vec<PortfolioAccount>,
+}
+
+impl Portfolio {
+    /// Groups the holdings of a response by account, resolving the name and ticker of each holding's security and computing its weight within the account.
+    ///
+    /// Holdings whose security is marked is_cash_equivalent are listed in `cash` rather than `positions`. Holdings whose security is missing from the response are treated as invested positions.
+    ///
+    /// * `resp` - The response of `Client::get_holdings`.
+    pub fn from_response(resp: &GetHoldingsResponse) -> Portfolio {
+        let securities: HashMap<&str, &Security> = resp
+            .securities
+            .iter()
+            .map(|security| (&security.security_id[..], security))
+            .collect();
+        let mut account_ids: Vec<&str> = resp
+            .accounts
+            .iter()
+            .map(|account| &account.account_id[..])
+            .filter(|account_id| {
+                resp.holdings
+                    .iter()
+                    .any(|holding| holding.account_id == *account_id)
+            })
+            .collect();
+        for holding in &resp.holdings {
+            if !account_ids.contains(&&holding.account_id[..]) {
+                account_ids.push(&holding.account_id);
+            }
+        }
+
+        let accounts = account_ids
+            .into_iter()
+            .map(|account_id| {
+                let holdings: Vec<&Holding> = resp
+                    .holdings
+                    .iter()
+                    .filter(|holding| holding.account_id == account_id)
+                    .collect();
+                let mut total_value = Money::default();
+                for holding in &holdings {
+                    total_value += holding.institution_value;
+                }
+                let mut account = PortfolioAccount {
+                    account_id: account_id.to_string(),
+                    name: resp
+                        .accounts
+                        .iter()
+                        .find(|account| account.account_id == account_id)
+                        .map(|account| account.name.clone()),
+                    total_value,
+                    cash_value: Money::default(),
+                    cash: Vec::new(),
+                    positions: Vec::new(),
+                };
+                for holding in holdings {
+                    let security = securities.get(&holding.security_id[..]).copied();
+                    let position = Position {
+                        security_id: holding.security_id.clone(),
+                        name: security.and_then(|security| security.name.clone()),
+                        ticker_symbol: security.and_then(|security| security.ticker_symbol.clone()),
+                        quantity: holding.quantity,
+                        institution_value: holding.institution_value,
+                        weight: if total_value > Money::default() {
+                            Some(holding.institution_value / total_value)
+                        } else {
+                            None
+                        },
+                    };
+                    if security.is_some_and(|security| security.is_cash_equivalent) {
+                        account.cash_value += holding.institution_value;
+                        account.cash.push(position);
+                    } else {
+                        account.positions.push(position);
+                    }
+                }
+                account
+            })
+            .collect();
+        Portfolio { accounts }
+    }
+}
+
 impl Client {
     /// Get Investment holdings.
     ///
@@ -106,8 +297,8 @@ impl Client {
         self.send_request(
             "investments/holdings/get",
             &GetHoldingsRequest {
-                client_id: &self.client_id,
-                secret: &self.secret,
+                client_id: self.client_id(),
+                secret: self.secret(),
                 access_token,
                 options,
             },
@@ -120,12 +311,155 @@ impl Client {
 mod tests {
     use super::*;
     use crate::client::tests::{get_test_client, SANDBOX_INSTITUTION, TEST_PRODUCTS};
+    use crate::fixtures::{account_fixture, holding_fixture, merge, security_fixture};
+
+    #[test]
+    fn test_stale_securities() {
+        let today = Utc::now().naive_utc().date();
+        let resp: GetHoldingsResponse = serde_json::from_value(serde_json::json!({
+            "request_id": "l68wb8zpS0hqmsJ",
+            "accounts": [],
+            "holdings": [],
+            "securities": [
+                merge(
+                    security_fixture("fresh"),
+                    serde_json::json!({"close_price": 10, "close_price_as_of": today - chrono::Duration::days(1)}),
+                ),
+                merge(
+                    security_fixture("stale"),
+                    serde_json::json!({"close_price": 10, "close_price_as_of": today - chrono::Duration::days(30)}),
+                ),
+                merge(security_fixture("undated"), serde_json::json!({"close_price": 10})),
+                security_fixture("unpriced"),
+            ],
+            "item": {
+                "item_id": "Ed6bjNrDLJfGvZWwnkQlfxwoNz54B5C97ejBr",
+                "update_type": "background"
+            }
+        }))
+        .unwrap();
+        let stale: Vec<&str> = resp
+            .stale_securities(7)
+            .iter()
+            .map(|security| security.security_id.as_str())
+            .collect();
+        assert_eq!(stale, vec!["stale", "undated"]);
+        assert!(!resp.securities[1].price_is_stale(today - chrono::Duration::days(30), 0));
+    }
+
+    #[test]
+    fn test_portfolio() {
+        let resp: GetHoldingsResponse = serde_json::from_value(serde_json::json!({
+            "request_id": "l68wb8zpS0hqmsJ",
+            "accounts": [
+                merge(
+                    account_fixture("ira", "investment", Some("ira")),
+                    serde_json::json!({"name": "Plaid IRA", "balances": {"available": null, "current": 100}}),
+                ),
+                account_fixture("checking", "depository", Some("checking")),
+            ],
+            "holdings": [
+                holding_fixture("ira", "stock", 75),
+                holding_fixture("ira", "cash", 25),
+                holding_fixture("unknown", "missing", 0),
+            ],
+            "securities": [
+                merge(
+                    security_fixture("stock"),
+                    serde_json::json!({"name": "ACME Inc.", "ticker_symbol": "ACME"}),
+                ),
+                merge(
+                    security_fixture("cash"),
+                    serde_json::json!({"ticker_symbol": "CUR:USD", "is_cash_equivalent": true}),
+                ),
+            ],
+            "item": {
+                "item_id": "Ed6bjNrDLJfGvZWwnkQlfxwoNz54B5C97ejBr",
+                "update_type": "background"
+            }
+        }))
+        .unwrap();
+
+        let portfolio = Portfolio::from_response(&resp);
+        assert_eq!(portfolio.accounts.len(), 2);
+        let ira = &portfolio.accounts[0];
+        assert_eq!(ira.name.as_deref(), Some("Plaid IRA"));
+        assert_eq!(ira.total_value, Money::from(100));
+        assert_eq!(ira.cash_value, Money::from(25));
+        assert_eq!(ira.positions.len(), 1);
+        assert_eq!(ira.positions[0].ticker_symbol.as_deref(), Some("ACME"));
+        assert_eq!(ira.positions[0].name.as_deref(), Some("ACME Inc."));
+        assert_eq!(
+            ira.positions[0].weight,
+            Some(Money::from(75) / Money::from(100))
+        );
+        assert_eq!(ira.cash.len(), 1);
+        assert_eq!(ira.cash[0].security_id, "cash");
+
+        let unknown = &portfolio.accounts[1];
+        assert_eq!(unknown.account_id, "unknown");
+        assert!(unknown.name.is_none());
+        assert!(unknown.positions[0].name.is_none());
+        assert!(unknown.positions[0].weight.is_none());
+    }
+
+    #[test]
+    fn test_holdings_with_securities() {
+        let resp: GetHoldingsResponse = serde_json::from_value(serde_json::json!({
+            "request_id": "l68wb8zpS0hqmsJ",
+            "accounts": [],
+            "holdings": [
+                holding_fixture("JqMLm4rJwpF6gMPJwBqdh9ZjjPvvpDcb7kDK1", "priced", 20),
+                holding_fixture("JqMLm4rJwpF6gMPJwBqdh9ZjjPvvpDcb7kDK1", "private", 20),
+                holding_fixture("k67E4xKvMlhmleEa4pg9hlwGGNnnEeixPolGm", "missing", 20),
+            ],
+            "securities": [
+                merge(
+                    security_fixture("priced"),
+                    serde_json::json!({"proxy_security_id": "proxy", "close_price": 10}),
+                ),
+                merge(
+                    security_fixture("private"),
+                    serde_json::json!({"proxy_security_id": "proxy"}),
+                ),
+                merge(security_fixture("proxy"), serde_json::json!({"close_price": 12})),
+            ],
+            "item": {
+                "item_id": "Ed6bjNrDLJfGvZWwnkQlfxwoNz54B5C97ejBr",
+                "institution_id": "ins_3",
+                "webhook": null,
+                "error": null,
+                "available_products": [],
+                "billed_products": [],
+                "consent_expiration_time": null,
+                "update_type": "background"
+            }
+        }))
+        .unwrap();
+
+        let pairs = resp.holdings_with_securities();
+        assert_eq!(pairs.len(), 3);
+        assert_eq!(pairs[0].1.unwrap().security_id, "priced");
+        assert_eq!(pairs[1].1.unwrap().security_id, "proxy");
+        assert!(pairs[2].1.is_none());
+
+        let values = resp.value_by_account();
+        assert_eq!(values.len(), 2);
+        assert_eq!(
+            values["JqMLm4rJwpF6gMPJwBqdh9ZjjPvvpDcb7kDK1"],
+            Money::from(40)
+        );
+        assert_eq!(
+            values["k67E4xKvMlhmleEa4pg9hlwGGNnnEeixPolGm"],
+            Money::from(20)
+        );
+    }
 
     #[tokio::test]
     async fn test_get_holdings() {
         let client = get_test_client();
         let sandbox_resp = client
-            .create_sandbox_public_token(SANDBOX_INSTITUTION, TEST_PRODUCTS)
+            .create_sandbox_public_token(SANDBOX_INSTITUTION, TEST_PRODUCTS, None)
             .await
             .unwrap();
         let token_resp = client
@@ -140,7 +474,7 @@ mod tests {
         assert_ne!(resp.accounts.len(), 0);
         assert_ne!(resp.securities.len(), 0);
         assert_ne!(resp.holdings.len(), 0);
-        assert_eq!(resp.item.item_id.is_empty(), false);
+        assert!(!resp.item.item_id.is_empty());
 
         let resp = client
             .get_holdings(
@@ -153,6 +487,6 @@ mod tests {
             .unwrap();
         assert_ne!(resp.accounts.len(), 0);
         assert_ne!(resp.holdings.len(), 0);
-        assert_eq!(resp.item.item_id.is_empty(), false);
+        assert!(!resp.item.item_id.is_empty());
     }
 }
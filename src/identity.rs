@@ -1,23 +1,28 @@
 use serde::{Deserialize, Serialize};
 
-use crate::accounts::AccountBalances;
+use crate::accounts::{AccountBalances, HolderCategory, VerificationStatus};
 use crate::client::Client;
 use crate::errors::Result;
 use crate::item::Item;
+use crate::types::Money;
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Identity {
     /// A list of names associated with the account by the financial institution.
+    #[serde(default)]
     pub names: Vec<String>,
     /// A list of phone numbers associated with the account by the financial institution.
+    #[serde(default)]
     pub phone_numbers: Vec<PhoneNumber>,
     /// A list of email addresses associated with the account by the financial institution.
+    #[serde(default)]
     pub emails: Vec<Email>,
     /// Data about the various addresses associated with the account by the financial institution.
+    #[serde(default)]
     pub addresses: Vec<Address>,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Address {
     /// Data about the components comprising an address.
     pub data: AddressData,
@@ -25,7 +30,7 @@ pub struct Address {
     pub primary: Option<bool>,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct AddressData {
     /// The full city name
     pub city: String,
@@ -39,7 +44,7 @@ pub struct AddressData {
     pub country: String,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Email {
     /// The email address.
     pub data: String,
@@ -50,7 +55,7 @@ pub struct Email {
     pub r#type: String,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct PhoneNumber {
     /// The phone number.
     pub data: String,
@@ -77,7 +82,7 @@ pub struct GetIdentityOptions<'a> {
     pub account_ids: Option<&'a [&'a str]>,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct AccountWithOwners {
     /// Plaid’s unique identifier for the account.
     pub account_id: String,
@@ -94,12 +99,14 @@ pub struct AccountWithOwners {
     /// Possible values: 401a, 401k, 403B, 457b, 529, brokerage, cash isa, education savings account, gic, health reimbursement arrangement, hsa, isa, ira, lif, lira, lrif, lrsp, non-taxable brokerage account, other, prif, rdsp, resp, rlif, rrif, pension, profit sharing plan, retirement, roth, roth 401k, rrsp, sep ira, simple ira, sipp, stock plan, thrift savings plan, tfsa, trust, ugma, utma, variable annuity, credit card, paypal, cd, checking, savings, money market, prepaid, auto, commercial, construction, consumer, home, home equity, loan, mortgage, overdraft, line of credit, student, cash management, keogh, mutual fund, recurring, rewards, safe deposit, sarsep
     pub subtype: Option<String>,
     /// The current verification status of an Auth Item initiated through Automated or Manual micro-deposits.  Returned for Auth Items only.
-    pub verification_status: Option<String>,
+    pub verification_status: Option<VerificationStatus>,
+    /// Indicates the account's categorization as either a personal or a business account. Only returned by some institutions.
+    pub holder_category: Option<HolderCategory>,
     /// Data returned by the financial institution about the account owner or owners.
     pub owners: Vec<Identity>,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct GetIdentityResponse {
     /// A unique identifier for the request, which can be used for troubleshooting. This identifier, like all Plaid identifiers, is case sensitive.
     pub request_id: String,
@@ -109,6 +116,135 @@ pub struct GetIdentityResponse {
     pub item: Item,
 }
 
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct IdentityMatchAddress<'a> {
+    /// The full street address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub street: Option<&'a str>,
+    /// The full city name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<&'a str>,
+    /// The region or state.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<&'a str>,
+    /// The postal code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub postal_code: Option<&'a str>,
+    /// The ISO 3166-1 alpha-2 country code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<&'a str>,
+}
+
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct IdentityMatchUser<'a> {
+    /// The user's full legal name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub legal_name: Option<&'a str>,
+    /// The user's phone number, in E.164 format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<&'a str>,
+    /// The user's email address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_address: Option<&'a str>,
+    /// The user's address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<IdentityMatchAddress<'a>>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct IdentityMatchOptions<'a> {
+    /// A list of account_ids to retrieve for the Item.
+    /// Note: An error will be returned if a provided account_id is not associated with the Item.
+    pub account_ids: Option<&'a [&'a str]>,
+}
+
+#[derive(Serialize)]
+struct IdentityMatchRequest<'a> {
+    client_id: &'a str,
+    secret: &'a str,
+    access_token: &'a str,
+    user: IdentityMatchUser<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    options: Option<IdentityMatchOptions<'a>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LegalNameMatchScore {
+    /// Represents the match score for name. 100 is a perfect score, 85-99 means a strong match, 50-84 is a partial match, less than 50 is a weak match and 0 is a complete mismatch.
+    pub score: Option<u8>,
+    /// first or last name completely matched.
+    pub is_first_name_or_last_name_match: Option<bool>,
+    /// If the name on either of the accounts matched a nickname of the other.
+    pub is_nickname_match: Option<bool>,
+    /// If the name on either of the accounts was detected to be a business name.
+    pub is_business_name_detected: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MatchScore {
+    /// Match score for the field. 100 is a perfect match and 0 is a no match.
+    pub score: Option<u8>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AddressMatchScore {
+    /// Match score for the address. 100 is a perfect match, 50-99 is a partial match and 0 is a no match.
+    pub score: Option<u8>,
+    /// Whether the postal code matched.
+    pub is_postal_code_match: Option<bool>,
+}
+
+/// The balances of an account returned by /identity/match. Plaid does not return balances from this endpoint, so every field is null.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct IdentityMatchBalances {
+    /// The amount of funds available to be withdrawn from the account.
+    pub available: Option<Money>,
+    /// The total amount of funds in or owed by the account.
+    pub current: Option<Money>,
+    /// The credit limit or overdraft limit of the account.
+    pub limit: Option<Money>,
+    /// The ISO-4217 currency code of the balance.
+    pub iso_currency_code: Option<String>,
+    /// The unofficial currency code associated with the balance.
+    pub unofficial_currency_code: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AccountWithMatchScores {
+    /// Plaid’s unique identifier for the account.
+    pub account_id: String,
+    /// A set of fields describing the balance for an account. Always null for /identity/match.
+    pub balances: IdentityMatchBalances,
+    /// The last 2-4 alphanumeric characters of an account's official account number.
+    pub mask: Option<String>,
+    /// The name of the account, either assigned by the user or by the financial institution itself
+    pub name: String,
+    /// The official name of the account as given by the financial institution
+    pub official_name: Option<String>,
+    /// Possible values: investment, credit, depository, loan, brokerage, other
+    pub r#type: String,
+    /// The account subtype, see [`AccountSubtype`].
+    pub subtype: Option<String>,
+    /// Score found by matching the name provided by the user with the institution's records.
+    pub legal_name: Option<LegalNameMatchScore>,
+    /// Score found by matching the phone number provided by the user with the institution's records.
+    pub phone_number: Option<MatchScore>,
+    /// Score found by matching the email address provided by the user with the institution's records.
+    pub email_address: Option<MatchScore>,
+    /// Score found by matching the address provided by the user with the institution's records.
+    pub address: Option<AddressMatchScore>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct IdentityMatchResponse {
+    /// A unique identifier for the request, which can be used for troubleshooting. This identifier, like all Plaid identifiers, is case sensitive.
+    pub request_id: String,
+    /// The accounts for which Identity match has been requested, with a match score for each field provided in `user`.
+    pub accounts: Vec<AccountWithMatchScores>,
+    /// Metadata about the Item.
+    pub item: Item,
+}
+
 impl Client {
     /// Retrieve identity data.
     ///
@@ -124,25 +260,56 @@ impl Client {
         self.send_request(
             "identity/get",
             &GetIdentityRequest {
-                client_id: &self.client_id,
-                secret: &self.secret,
+                client_id: self.client_id(),
+                secret: self.secret(),
                 access_token,
                 options,
             },
         )
         .await
     }
+
+    /// Retrieve identity match score.
+    ///
+    /// The /identity/match endpoint generates a match score, which indicates how well the provided identity data matches the identity information on file with the account holder's financial institution. Fields within the balances object will always be null when retrieved by /identity/match.
+    ///
+    /// * `access_token` - The access token associated with the Item data is being requested for.
+    /// * `user` - The user's legal name, phone number, email address and address used to perform fuzzy match.
+    /// * `options` - An optional object to filter /identity/match results.
+    pub async fn match_identity<'a>(
+        &self,
+        access_token: &str,
+        user: IdentityMatchUser<'a>,
+        options: Option<IdentityMatchOptions<'a>>,
+    ) -> Result<IdentityMatchResponse> {
+        self.send_request(
+            "identity/match",
+            &IdentityMatchRequest {
+                client_id: self.client_id(),
+                secret: self.secret(),
+                access_token,
+                user,
+                options,
+            },
+        )
+        .await
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::client::tests::{get_test_client, SANDBOX_INSTITUTION, TEST_PRODUCTS};
+    use super::*;
+    use crate::accounts::{AccountSubtype, HasAccountType};
+    use crate::client::tests::{
+        get_mock_client, get_test_client, SANDBOX_INSTITUTION, TEST_PRODUCTS,
+    };
+    use crate::fixtures::{account_fixture, merge};
 
     #[tokio::test]
     async fn test_get_identity() {
         let client = get_test_client();
         let sandbox_resp = client
-            .create_sandbox_public_token(SANDBOX_INSTITUTION, TEST_PRODUCTS)
+            .create_sandbox_public_token(SANDBOX_INSTITUTION, TEST_PRODUCTS, None)
             .await
             .unwrap();
         let token_resp = client
@@ -158,4 +325,111 @@ mod tests {
             assert_ne!(account.owners.len(), 0);
         }
     }
+
+    #[tokio::test]
+    async fn test_match_identity() {
+        let client = get_test_client();
+        let sandbox_resp = client
+            .create_sandbox_public_token(SANDBOX_INSTITUTION, TEST_PRODUCTS, None)
+            .await
+            .unwrap();
+        let token_resp = client
+            .exchange_public_token(&sandbox_resp.public_token)
+            .await
+            .unwrap();
+        let resp = client
+            .match_identity(
+                &token_resp.access_token,
+                IdentityMatchUser {
+                    legal_name: Some("Alberta Bobbeth Charleson"),
+                    email_address: Some("accountholder0@example.com"),
+                    ..Default::default()
+                },
+                None,
+            )
+            .await
+            .unwrap();
+        assert_ne!(resp.accounts.len(), 0);
+        for account in &resp.accounts {
+            assert!(account.legal_name.is_some());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_match_identity_mock() {
+        let (client, transport) = get_mock_client();
+        transport.push_json(
+            "identity/match",
+            reqwest::StatusCode::OK,
+            serde_json::json!({
+                "request_id": "3nARps6TOYtbACO",
+                "accounts": [merge(
+                    account_fixture("BxBXxLj1m4HMXBm9WZZmCWVbPjX16EHwv99vp", "depository", Some("checking")),
+                    serde_json::json!({
+                        "balances": {"available": null, "current": null, "limit": null, "iso_currency_code": null, "unofficial_currency_code": null},
+                        "legal_name": {
+                            "score": 90,
+                            "is_first_name_or_last_name_match": true,
+                            "is_nickname_match": true,
+                            "is_business_name_detected": false
+                        },
+                        "phone_number": null,
+                        "email_address": {"score": 100},
+                        "address": {"score": 100, "is_postal_code_match": true}
+                    }),
+                )],
+                "item": {
+                    "item_id": "Ed6bjNrDLJfGvZWwnkQlfxwoNz54B5C97ejBr",
+                    "update_type": "background"
+                }
+            }),
+        );
+
+        let resp = client
+            .match_identity(
+                "access-sandbox-123",
+                IdentityMatchUser {
+                    legal_name: Some("Alberta Bobbeth Charleson"),
+                    address: Some(IdentityMatchAddress {
+                        street: Some("2992 Cameron Road"),
+                        city: Some("Malakoff"),
+                        postal_code: Some("14236"),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+                Some(IdentityMatchOptions {
+                    account_ids: Some(&["BxBXxLj1m4HMXBm9WZZmCWVbPjX16EHwv99vp"]),
+                }),
+            )
+            .await
+            .unwrap();
+        let account = &resp.accounts[0];
+        assert_eq!(account.account_subtype(), Some(AccountSubtype::Checking));
+        assert!(account.balances.current.is_none());
+        let legal_name = account.legal_name.as_ref().unwrap();
+        assert_eq!(legal_name.score, Some(90));
+        assert_eq!(legal_name.is_nickname_match, Some(true));
+        assert_eq!(legal_name.is_business_name_detected, Some(false));
+        assert!(account.phone_number.is_none());
+        assert_eq!(account.email_address.as_ref().unwrap().score, Some(100));
+        assert_eq!(
+            account.address.as_ref().unwrap().is_postal_code_match,
+            Some(true)
+        );
+
+        let body: serde_json::Value =
+            serde_json::from_slice(&transport.requests()[0].body).unwrap();
+        assert_eq!(body["access_token"], "access-sandbox-123");
+        assert_eq!(body["user"]["legal_name"], "Alberta Bobbeth Charleson");
+        assert_eq!(
+            body["user"]["address"],
+            serde_json::json!({"street": "2992 Cameron Road", "city": "Malakoff", "postal_code": "14236"})
+        );
+        assert!(body["user"].get("email_address").is_none());
+        assert_eq!(
+            body["options"]["account_ids"],
+            serde_json::json!(["BxBXxLj1m4HMXBm9WZZmCWVbPjX16EHwv99vp"])
+        );
+    }
 }
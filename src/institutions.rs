@@ -1,9 +1,15 @@
+use base64::Engine;
+use chrono::{DateTime, Utc};
+use futures_util::stream::{self, StreamExt, TryStreamExt};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 use crate::client::Client;
-use crate::errors::Result;
+use crate::errors::{Error, Result};
+use crate::link_token::AccountFilters;
+use crate::types::{CountryCode, Product};
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Institution {
     /// Unique identifier for the institution
     pub institution_id: String,
@@ -25,6 +31,150 @@ pub struct Institution {
     pub routing_numbers: Option<Vec<String>>,
     /// Indicates that the institution has an OAuth login flow. This is primarily relevant to institutions with European country codes.
     pub oauth: bool,
+    /// The status of an institution is determined by the health of its Item logins, Transactions updates, Investments updates, Liabilities updates, Auth requests, Balance requests, and Identity requests. Only returned when include_status is true.
+    pub status: Option<InstitutionStatus>,
+    /// Metadata that captures what specific payment configurations an institution supports when making Payment Initiation requests. Only returned when include_payment_initiation_metadata is true.
+    pub payment_initiation_metadata: Option<PaymentInitiationMetadata>,
+    /// Metadata that captures information about the Auth features of an institution. Only returned when include_auth_metadata is true.
+    pub auth_metadata: Option<AuthMetadata>,
+}
+
+impl Institution {
+    /// Returns the institution's logo as raw PNG bytes, or None if there is no logo or it is not valid base64.
+    pub fn decode_logo(&self) -> Option<Vec<u8>> {
+        self.logo
+            .as_ref()
+            .and_then(|logo| base64::engine::general_purpose::STANDARD.decode(logo).ok())
+    }
+
+    /// Returns the institution's primary color as an (r, g, b) triple, or None if there is no color or it is not a `#rrggbb` hex string.
+    pub fn primary_color_rgb(&self) -> Option<(u8, u8, u8)> {
+        let hex = self.primary_color.as_ref()?;
+        let hex = hex.strip_prefix('#').unwrap_or(hex);
+        if hex.len() != 6 || !hex.is_ascii() {
+            return None;
+        }
+        let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+        Some((channel(0)?, channel(2)?, channel(4)?))
+    }
+}
+
+string_enum! {
+    /// The health of a product at an institution.
+    pub enum ProductStatusLevel {
+        /// The product is working normally.
+        Healthy => "HEALTHY",
+        /// The product is experiencing elevated error rates.
+        Degraded => "DEGRADED",
+        /// The product is not working.
+        Down => "DOWN",
+        _ => Other,
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ProductStatusBreakdown {
+    /// The percentage of login attempts that are successful, expressed as a decimal.
+    pub success: f64,
+    /// The percentage of logins that are failing due to an internal Plaid issue, expressed as a decimal.
+    pub error_plaid: f64,
+    /// The percentage of logins that are failing due to an issue in the institution's system, expressed as a decimal.
+    pub error_institution: f64,
+    /// How frequently data is being refreshed for the product. Possible values: NORMAL, DELAYED, STOPPED. Only returned for Transactions updates.
+    pub refresh_interval: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ProductStatus {
+    /// The status of the product.
+    pub status: ProductStatusLevel,
+    /// ISO 8601 formatted timestamp of the last status change for the institution.
+    pub last_status_change: DateTime<Utc>,
+    /// A detailed breakdown of the institution's performance for a request type.
+    pub breakdown: ProductStatusBreakdown,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct HealthIncidentUpdate {
+    /// The content of the update.
+    pub description: String,
+    /// The status of the incident. Possible values: INVESTIGATING, IDENTIFIED, SCHEDULED, RESOLVED, UNKNOWN.
+    pub status: String,
+    /// The date when the update was published, in ISO 8601 format.
+    pub updated_date: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct HealthIncident {
+    /// The start date of the incident, in ISO 8601 format.
+    pub start_date: DateTime<Utc>,
+    /// The end date of the incident, in ISO 8601 format.
+    pub end_date: Option<DateTime<Utc>>,
+    /// The title of the incident.
+    pub title: String,
+    /// Updates on the health incident.
+    #[serde(default)]
+    pub incident_updates: Vec<HealthIncidentUpdate>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InstitutionStatus {
+    /// A representation of the status health of a request type.
+    pub item_logins: Option<ProductStatus>,
+    /// A representation of the status health of Transactions updates.
+    pub transactions_updates: Option<ProductStatus>,
+    /// A representation of the status health of Auth requests.
+    pub auth: Option<ProductStatus>,
+    /// A representation of the status health of Identity requests.
+    pub identity: Option<ProductStatus>,
+    /// A representation of the status health of Investments updates.
+    pub investments_updates: Option<ProductStatus>,
+    /// A representation of the status health of Liabilities updates.
+    pub liabilities_updates: Option<ProductStatus>,
+    /// A representation of the status health of Liabilities requests.
+    pub liabilities: Option<ProductStatus>,
+    /// A representation of the status health of Investments requests.
+    pub investments: Option<ProductStatus>,
+    /// Details of recent health incidents associated with the institution.
+    pub health_incidents: Option<Vec<HealthIncident>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AuthSupportedMethods {
+    /// Indicates if instant auth is supported.
+    #[serde(default)]
+    pub instant_auth: bool,
+    /// Indicates if instant match is supported.
+    #[serde(default)]
+    pub instant_match: bool,
+    /// Indicates if automated microdeposits are supported.
+    #[serde(default)]
+    pub automated_micro_deposits: bool,
+    /// Indicates if instant microdeposits are supported.
+    #[serde(default)]
+    pub instant_micro_deposits: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AuthMetadata {
+    /// Metadata specifically related to which auth methods an institution supports.
+    pub supported_methods: Option<AuthSupportedMethods>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PaymentInitiationMetadata {
+    /// Indicates whether the institution supports payments from a different country.
+    #[serde(default)]
+    pub supports_international_payments: bool,
+    /// Indicates whether the institution supports SEPA Instant payments.
+    #[serde(default)]
+    pub supports_sepa_instant: bool,
+    /// A mapping of currency to maximum payment amount (denominated in the smallest unit of currency) supported by the institution.
+    #[serde(default)]
+    pub maximum_payment_amount: HashMap<String, String>,
+    /// Indicates whether the institution supports returning refund details when initiating a payment.
+    #[serde(default)]
+    pub supports_refund_details: bool,
 }
 
 #[derive(Serialize)]
@@ -33,17 +183,21 @@ struct GetInstitutionsRequest<'a> {
     secret: &'a str,
     count: i32,
     offset: i32,
-    country_codes: &'a [&'a str],
+    country_codes: &'a [CountryCode],
     #[serde(skip_serializing_if = "Option::is_none")]
     options: Option<GetInstitutionsOptions>,
 }
 
-#[derive(Serialize)]
+/// The maximum number of institutions /institutions/get returns per call, also used as the page size when paginating.
+const INSTITUTIONS_PAGE_SIZE: i32 = 500;
+/// The maximum number of /institutions/get_by_id requests `get_institutions_by_ids` keeps in flight.
+const INSTITUTIONS_BY_ID_CONCURRENCY: usize = 8;
+
+#[derive(Serialize, Default, Clone)]
 pub struct GetInstitutionsOptions {
     /// Filter the Institutions based on which products they support.
-    /// Possible values: assets, auth, balance, identity, investments, liabilities, payment_initiation, transactions, credit_details, income, deposit_switch
     #[serde(skip_serializing_if = "Vec::is_empty")]
-    pub products: Vec<String>,
+    pub products: Vec<Product>,
     /// Specify an array of routing numbers to filter institutions.
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub routing_numbers: Vec<String>,
@@ -54,18 +208,7 @@ pub struct GetInstitutionsOptions {
     pub include_optional_metadata: bool,
 }
 
-impl Default for GetInstitutionsOptions {
-    fn default() -> Self {
-        Self {
-            products: Vec::new(),
-            include_optional_metadata: false,
-            oauth: None,
-            routing_numbers: Vec::new(),
-        }
-    }
-}
-
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub struct GetInstitutionsResponse {
     /// A unique identifier for the request, which can be used for troubleshooting. This identifier, like all Plaid identifiers, is case sensitive.
     pub request_id: String,
@@ -80,32 +223,29 @@ struct GetInstitutionByIdRequest<'a> {
     client_id: &'a str,
     secret: &'a str,
     institution_id: &'a str,
-    country_codes: &'a [&'a str],
+    country_codes: &'a [CountryCode],
     #[serde(skip_serializing_if = "Option::is_none")]
     options: Option<GetInstitutionByIdOptions>,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Debug, Clone, Default)]
 pub struct GetInstitutionByIdOptions {
     /// When true, return an institution's logo, brand color, and URL. When available, the bank's logo is returned as a base64 encoded 152x152 PNG, the brand color is in hexadecimal format. The default value is false.
     pub include_optional_metadata: bool,
     /// If true, the response will include status information about the institution. Default value is false.
     pub include_status: bool,
+    /// When true, returns metadata related to the Auth product indicating which auth methods are supported. The default value is false.
+    pub include_auth_metadata: bool,
+    /// When true, returns metadata related to the Payment Initiation product indicating which payment configurations are supported. The default value is false.
+    pub include_payment_initiation_metadata: bool,
 }
 
-impl Default for GetInstitutionByIdOptions {
-    fn default() -> Self {
-        Self {
-            include_optional_metadata: false,
-            include_status: false,
-        }
-    }
-}
-
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub struct GetInstitutionByIdResponse {
-    request_id: String,
-    institution: Institution,
+    /// A unique identifier for the request, which can be used for troubleshooting. This identifier, like all Plaid identifiers, is case sensitive.
+    pub request_id: String,
+    /// Details relating to a specific financial institution
+    pub institution: Institution,
 }
 
 #[derive(Serialize)]
@@ -113,33 +253,40 @@ struct SearchInstitutionsRequest<'a> {
     client_id: &'a str,
     secret: &'a str,
     query: &'a str,
-    country_codes: &'a [&'a str],
-    products: &'a [&'a str],
+    country_codes: &'a [CountryCode],
+    products: &'a [Product],
     #[serde(skip_serializing_if = "Option::is_none")]
     options: Option<SearchInstitutionsOptions>,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Debug, Clone, Default)]
 pub struct SearchInstitutionsOptions {
-    include_optional_metadata: bool,
-    // account_filter:
+    /// When true, return the institution's homepage URL, logo and primary brand color.
+    pub include_optional_metadata: bool,
+    /// Limit results to institutions that support the given account types and subtypes. This is only supported for the auth and transactions products.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_filter: Option<AccountFilters>,
+    /// Limit results to institutions with or without OAuth login flows. This is primarily relevant to institutions with European country codes.
     #[serde(skip_serializing_if = "Option::is_none")]
-    oauth: Option<bool>,
+    pub oauth: Option<bool>,
 }
 
-impl Default for SearchInstitutionsOptions {
-    fn default() -> Self {
-        Self {
-            include_optional_metadata: false,
-            oauth: None,
-        }
-    }
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SearchInstitutionsResponse {
+    /// A unique identifier for the request, which can be used for troubleshooting. This identifier, like all Plaid identifiers, is case sensitive.
+    pub request_id: String,
+    /// An array of institutions matching the search criteria
+    pub institutions: Vec<Institution>,
 }
 
-#[derive(Deserialize, Debug)]
-pub struct SearchInstitutionsResponse {
-    request_id: String,
-    institutions: Vec<Institution>,
+/// Checks that at least one country code was given, which every endpoint taking country_codes requires.
+pub(crate) fn validate_country_codes(country_codes: &[CountryCode]) -> Result<()> {
+    if country_codes.is_empty() {
+        return Err(Error::InvalidRequest(
+            "country_codes must not be empty".to_string(),
+        ));
+    }
+    Ok(())
 }
 
 impl Client {
@@ -153,14 +300,15 @@ impl Client {
     pub async fn get_institution_by_id(
         &self,
         institution_id: &str,
-        country_codes: &[&str],
+        country_codes: &[CountryCode],
         options: Option<GetInstitutionByIdOptions>,
     ) -> Result<GetInstitutionByIdResponse> {
+        validate_country_codes(country_codes)?;
         self.send_request(
             "institutions/get_by_id",
             &GetInstitutionByIdRequest {
-                client_id: &self.client_id,
-                secret: &self.secret,
+                client_id: self.client_id(),
+                secret: self.secret(),
                 institution_id,
                 country_codes,
                 options,
@@ -182,14 +330,15 @@ impl Client {
         &self,
         count: i32,
         offset: i32,
-        country_codes: &[&str],
+        country_codes: &[CountryCode],
         options: Option<GetInstitutionsOptions>,
     ) -> Result<GetInstitutionsResponse> {
+        validate_country_codes(country_codes)?;
         self.send_request(
             "institutions/get",
             &GetInstitutionsRequest {
-                client_id: &self.client_id,
-                secret: &self.secret,
+                client_id: self.client_id(),
+                secret: self.secret(),
                 count,
                 offset,
                 country_codes,
@@ -199,6 +348,60 @@ impl Client {
         .await
     }
 
+    /// Get details of several institutions.
+    ///
+    /// Calls /institutions/get_by_id for every institution, with at most 8 requests in flight at once. The institutions are returned in the same order as `institution_ids`. If any request fails, the first error is returned.
+    ///
+    /// * `institution_ids` - The IDs of the institutions to get details about.
+    /// * `country_codes` - Specify an array of Plaid-supported country codes this institution supports, using the ISO-3166-1 alpha-2 country code standard.
+    /// * `options` - Specifies optional parameters for every /institutions/get_by_id request.
+    pub async fn get_institutions_by_ids(
+        &self,
+        institution_ids: &[&str],
+        country_codes: &[CountryCode],
+        options: Option<GetInstitutionByIdOptions>,
+    ) -> Result<Vec<Institution>> {
+        validate_country_codes(country_codes)?;
+        stream::iter(institution_ids)
+            .map(|institution_id| {
+                self.get_institution_by_id(institution_id, country_codes, options.clone())
+            })
+            .buffered(INSTITUTIONS_BY_ID_CONCURRENCY)
+            .map_ok(|resp| resp.institution)
+            .try_collect()
+            .await
+    }
+
+    /// Get all institutions.
+    ///
+    /// Repeatedly calls /institutions/get, paging through the results until every institution has been fetched. Paging stops early if a page comes back short, which guards against the total changing between calls.
+    ///
+    /// * `country_codes` - Specify an array of Plaid-supported country codes this institution supports, using the ISO-3166-1 alpha-2 country code standard.
+    /// * `options` - An optional object to filter /institutions/get results.
+    pub async fn get_all_institutions(
+        &self,
+        country_codes: &[CountryCode],
+        options: Option<GetInstitutionsOptions>,
+    ) -> Result<Vec<Institution>> {
+        let mut institutions = Vec::new();
+        loop {
+            let mut resp = self
+                .get_institutions(
+                    INSTITUTIONS_PAGE_SIZE,
+                    institutions.len() as i32,
+                    country_codes,
+                    options.clone(),
+                )
+                .await?;
+            let page_len = resp.institutions.len();
+            institutions.append(&mut resp.institutions);
+            if page_len < INSTITUTIONS_PAGE_SIZE as usize || institutions.len() as i32 >= resp.total
+            {
+                return Ok(institutions);
+            }
+        }
+    }
+
     /// Search institutions.
     ///
     /// Returns a JSON response containing details for institutions that match the query parameters, up to a maximum of ten institutions per query.
@@ -210,15 +413,16 @@ impl Client {
     pub async fn search_institutions(
         &self,
         query: &str,
-        products: &[&str],
-        country_codes: &[&str],
+        products: &[Product],
+        country_codes: &[CountryCode],
         options: Option<SearchInstitutionsOptions>,
     ) -> Result<SearchInstitutionsResponse> {
+        validate_country_codes(country_codes)?;
         self.send_request(
             "institutions/search",
             &SearchInstitutionsRequest {
-                client_id: &self.client_id,
-                secret: &self.secret,
+                client_id: self.client_id(),
+                secret: self.secret(),
                 query,
                 products,
                 country_codes,
@@ -232,20 +436,295 @@ impl Client {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::accounts::AccountSubtype;
     use crate::client::tests::{get_test_client, SANDBOX_INSTITUTION_QUERY};
+    use crate::link_token::AccountSubtypeFilter;
+    use reqwest::StatusCode;
+
+    #[test]
+    fn test_institution_status() {
+        let institution: Institution = serde_json::from_value(serde_json::json!({
+            "institution_id": "ins_12",
+            "name": "Fidelity",
+            "products": ["auth"],
+            "country_codes": ["US"],
+            "oauth": false,
+            "status": {
+                "item_logins": {
+                    "status": "HEALTHY",
+                    "last_status_change": "2019-02-15T15:53:00Z",
+                    "breakdown": {"success": 0.9, "error_plaid": 0.01, "error_institution": 0.09}
+                },
+                "transactions_updates": {
+                    "status": "SLOW",
+                    "last_status_change": "2019-02-12T08:22:00Z",
+                    "breakdown": {"success": 0.95, "error_plaid": 0.02, "error_institution": 0.03, "refresh_interval": "NORMAL"}
+                }
+            },
+            "auth_metadata": {
+                "supported_methods": {
+                    "instant_auth": true,
+                    "instant_match": false,
+                    "automated_micro_deposits": true,
+                    "instant_micro_deposits": false
+                }
+            }
+        }))
+        .unwrap();
+        let status = institution.status.unwrap();
+        assert_eq!(
+            status.item_logins.unwrap().status,
+            ProductStatusLevel::Healthy
+        );
+        assert_eq!(
+            status.transactions_updates.unwrap().status,
+            ProductStatusLevel::Other("SLOW".to_string())
+        );
+        assert!(status.auth.is_none());
+        assert!(
+            institution
+                .auth_metadata
+                .unwrap()
+                .supported_methods
+                .unwrap()
+                .instant_auth
+        );
+        assert!(institution.payment_initiation_metadata.is_none());
+    }
+
+    #[test]
+    fn test_partial_institution_metadata() {
+        let institution: Institution = serde_json::from_value(serde_json::json!({
+            "institution_id": "ins_117181",
+            "name": "Monzo",
+            "products": ["payment_initiation"],
+            "country_codes": ["GB"],
+            "oauth": true,
+            "auth_metadata": {"supported_methods": {"instant_auth": true}},
+            "payment_initiation_metadata": {"supports_international_payments": true}
+        }))
+        .unwrap();
+        let methods = institution
+            .auth_metadata
+            .unwrap()
+            .supported_methods
+            .unwrap();
+        assert!(methods.instant_auth);
+        assert!(!methods.instant_micro_deposits);
+        let metadata = institution.payment_initiation_metadata.unwrap();
+        assert!(metadata.supports_international_payments);
+        assert!(!metadata.supports_sepa_instant);
+        assert!(metadata.maximum_payment_amount.is_empty());
+    }
+
+    #[test]
+    fn test_decode_logo_and_primary_color() {
+        let mut institution: Institution = serde_json::from_value(serde_json::json!({
+            "institution_id": "ins_12",
+            "name": "Fidelity",
+            "products": ["auth"],
+            "country_codes": ["US"],
+            "primary_color": "#1c4e9c",
+            "logo": "iVBORw0KGgo=",
+            "oauth": false
+        }))
+        .unwrap();
+        assert_eq!(
+            institution.decode_logo(),
+            Some(vec![0x89, b'P', b'N', b'G', 0x0d, 0x0a, 0x1a, 0x0a])
+        );
+        assert_eq!(institution.primary_color_rgb(), Some((0x1c, 0x4e, 0x9c)));
+
+        institution.logo = Some("not base64!".to_string());
+        institution.primary_color = Some("#1c4e9".to_string());
+        assert!(institution.decode_logo().is_none());
+        assert!(institution.primary_color_rgb().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_empty_country_codes() {
+        let client = Client::new(
+            "client_id".to_string(),
+            "secret".to_string(),
+            crate::client::Environment::Sandbox,
+        );
+        let err = client.get_institutions(2, 1, &[], None).await.unwrap_err();
+        assert!(matches!(err, Error::InvalidRequest(_)));
+        assert_eq!(
+            err.to_string(),
+            "Error - Invalid request - country_codes must not be empty"
+        );
+        let err = client
+            .get_institution_by_id("ins_12", &[], None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::InvalidRequest(_)));
+    }
+
+    #[tokio::test]
+    async fn test_get_all_institutions() {
+        let (client, transport) = crate::client::tests::get_mock_client();
+        let page = |start: usize, len: usize, total: i32| {
+            serde_json::json!({
+                "request_id": "uqVeBN1SMdf5FpS",
+                "institutions": (start..start + len)
+                    .map(|i| serde_json::json!({
+                        "institution_id": format!("ins_{}", i),
+                        "name": "Institution",
+                        "products": ["auth"],
+                        "country_codes": ["US"],
+                        "oauth": false
+                    }))
+                    .collect::<Vec<_>>(),
+                "total": total
+            })
+        };
+        transport.push_json("institutions/get", StatusCode::OK, page(0, 500, 600));
+        // The total grows between pages, so paging stops on the short page.
+        transport.push_json("institutions/get", StatusCode::OK, page(500, 100, 700));
+
+        let institutions = client
+            .get_all_institutions(&[CountryCode::US], None)
+            .await
+            .unwrap();
+        assert_eq!(institutions.len(), 600);
+        assert_eq!(institutions[599].institution_id, "ins_599");
+
+        let offsets: Vec<serde_json::Value> = transport
+            .requests()
+            .iter()
+            .map(|req| {
+                serde_json::from_slice::<serde_json::Value>(&req.body).unwrap()["offset"].clone()
+            })
+            .collect();
+        assert_eq!(offsets, vec![serde_json::json!(0), serde_json::json!(500)]);
+    }
+
+    #[tokio::test]
+    async fn test_get_institutions_by_ids() {
+        // Answers later institutions sooner, so responses complete in the reverse of the request order.
+        #[derive(Debug)]
+        struct ReverseLatencyTransport;
+
+        #[async_trait::async_trait]
+        impl crate::transport::Transport for ReverseLatencyTransport {
+            async fn send(
+                &self,
+                request: crate::transport::TransportRequest,
+            ) -> Result<crate::transport::TransportResponse> {
+                let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+                let id = body["institution_id"].as_str().unwrap().to_string();
+                let index: u64 = id.trim_start_matches("ins_").parse().unwrap();
+                tokio::time::sleep(std::time::Duration::from_millis(5 * (10 - index))).await;
+                Ok(crate::transport::TransportResponse {
+                    status: StatusCode::OK,
+                    headers: reqwest::header::HeaderMap::new(),
+                    body: serde_json::to_vec(&serde_json::json!({
+                        "request_id": "m8MDnv9okwxFNBV",
+                        "institution": {
+                            "institution_id": id,
+                            "name": "Institution",
+                            "products": ["auth"],
+                            "country_codes": ["US"],
+                            "oauth": false
+                        }
+                    }))
+                    .unwrap(),
+                })
+            }
+        }
+
+        let client = Client::from_transport(
+            "client_id".to_string(),
+            "secret".to_string(),
+            crate::client::Environment::Sandbox,
+            std::sync::Arc::new(ReverseLatencyTransport),
+        );
+        let ids: Vec<String> = (0..10).map(|i| format!("ins_{}", i)).collect();
+        let ids: Vec<&str> = ids.iter().map(String::as_str).collect();
+        let institutions = client
+            .get_institutions_by_ids(&ids, &[CountryCode::US], None)
+            .await
+            .unwrap();
+        let fetched: Vec<&str> = institutions
+            .iter()
+            .map(|institution| institution.institution_id.as_str())
+            .collect();
+        assert_eq!(fetched, ids);
+
+        let (client, transport) = crate::client::tests::get_mock_client();
+        transport.push_json(
+            "institutions/get_by_id",
+            StatusCode::BAD_REQUEST,
+            serde_json::json!({
+                "error_type": "INVALID_INPUT",
+                "error_code": "INVALID_INSTITUTION",
+                "error_message": "invalid institution_id provided",
+                "display_message": null,
+                "request_id": "m8MDnv9okwxFNBV"
+            }),
+        );
+        match client
+            .get_institutions_by_ids(&["ins_0"], &[CountryCode::US], None)
+            .await
+        {
+            Err(Error::Plaid(err)) => assert_eq!(err.error_code, "INVALID_INSTITUTION"),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_search_institutions_account_filter() {
+        let (client, transport) = crate::client::tests::get_mock_client();
+        transport.push_json(
+            "institutions/search",
+            StatusCode::OK,
+            serde_json::json!({"request_id": "Ggmk0enW4smO2Tp", "institutions": []}),
+        );
+        client
+            .search_institutions(
+                SANDBOX_INSTITUTION_QUERY,
+                &[Product::Auth],
+                &[CountryCode::US],
+                Some(SearchInstitutionsOptions {
+                    account_filter: Some(AccountFilters {
+                        depository: Some(AccountSubtypeFilter {
+                            account_subtypes: vec![AccountSubtype::Checking],
+                        }),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }),
+            )
+            .await
+            .unwrap();
+
+        let body: serde_json::Value =
+            serde_json::from_slice(&transport.requests()[0].body).unwrap();
+        assert_eq!(
+            body["options"],
+            serde_json::json!({
+                "include_optional_metadata": false,
+                "account_filter": {"depository": {"account_subtypes": ["checking"]}}
+            })
+        );
+    }
 
     #[tokio::test]
     async fn test_get_institutions() {
         let client = get_test_client();
 
-        let resp = client.get_institutions(2, 1, &["US"], None).await.unwrap();
+        let resp = client
+            .get_institutions(2, 1, &[CountryCode::US], None)
+            .await
+            .unwrap();
         assert_eq!(resp.institutions.len(), 2);
 
         let resp = client
             .get_institutions(
                 2,
                 1,
-                &["US"],
+                &[CountryCode::US],
                 Some(GetInstitutionsOptions {
                     include_optional_metadata: true,
                     ..Default::default()
@@ -255,7 +734,7 @@ mod tests {
             .unwrap();
         assert_eq!(resp.institutions.len(), 2);
         for institution in &resp.institutions {
-            assert_eq!(institution.url.is_some(), true);
+            assert!(institution.url.is_some());
             assert_ne!(institution.url.as_ref().unwrap().len(), 0);
         }
 
@@ -263,7 +742,7 @@ mod tests {
             .get_institutions(
                 2,
                 1,
-                &["GB"],
+                &[CountryCode::GB],
                 Some(GetInstitutionsOptions {
                     oauth: Some(true),
                     ..Default::default()
@@ -274,13 +753,13 @@ mod tests {
         assert_eq!(resp.institutions.len(), 2);
 
         let resp = client.get_institutions(2, 1, &[], None).await;
-        assert_eq!(resp.is_err(), true);
+        assert!(resp.is_err());
 
         let resp = client
             .get_institutions(
                 1,
                 0,
-                &["US"],
+                &[CountryCode::US],
                 Some(GetInstitutionsOptions {
                     routing_numbers: vec!["021200339".to_string(), "052001633".to_string()],
                     ..Default::default()
@@ -296,16 +775,21 @@ mod tests {
         let client = get_test_client();
 
         let resp = client
-            .search_institutions(SANDBOX_INSTITUTION_QUERY, &["transactions"], &["US"], None)
+            .search_institutions(
+                SANDBOX_INSTITUTION_QUERY,
+                &[Product::Transactions],
+                &[CountryCode::US],
+                None,
+            )
             .await
             .unwrap();
-        assert!(resp.institutions.len() > 0);
+        assert!(!resp.institutions.is_empty());
 
         let resp = client
             .search_institutions(
                 SANDBOX_INSTITUTION_QUERY,
-                &["transactions"],
-                &["US"],
+                &[Product::Transactions],
+                &[CountryCode::US],
                 Some(SearchInstitutionsOptions {
                     include_optional_metadata: true,
                     ..Default::default()
@@ -313,16 +797,21 @@ mod tests {
             )
             .await
             .unwrap();
-        assert!(resp.institutions.len() > 0);
+        assert!(!resp.institutions.is_empty());
         for institution in &resp.institutions {
-            assert_eq!(institution.url.is_some(), true);
+            assert!(institution.url.is_some());
             assert_ne!(institution.url.as_ref().unwrap().len(), 0);
         }
 
         let resp = client
-            .search_institutions(SANDBOX_INSTITUTION_QUERY, &["transactions"], &[""], None)
+            .search_institutions(
+                SANDBOX_INSTITUTION_QUERY,
+                &[Product::Transactions],
+                &[],
+                None,
+            )
             .await;
-        assert_eq!(resp.is_err(), true);
+        assert!(resp.is_err());
     }
 
     #[tokio::test]
@@ -330,15 +819,15 @@ mod tests {
         let client = get_test_client();
 
         let resp = client
-            .get_institution_by_id("ins_12", &["US"], None)
+            .get_institution_by_id("ins_12", &[CountryCode::US], None)
             .await
             .unwrap();
-        assert!(resp.institution.products.len() > 0);
+        assert!(!resp.institution.products.is_empty());
 
         let resp = client
             .get_institution_by_id(
                 "ins_12",
-                &["US"],
+                &[CountryCode::US],
                 Some(GetInstitutionByIdOptions {
                     include_optional_metadata: true,
                     ..Default::default()
@@ -346,11 +835,26 @@ mod tests {
             )
             .await
             .unwrap();
-        assert!(resp.institution.products.len() > 0);
-        assert_eq!(resp.institution.url.is_some(), true);
+        assert!(!resp.institution.products.is_empty());
+        assert!(resp.institution.url.is_some());
         assert_ne!(resp.institution.url.as_ref().unwrap().len(), 0);
 
+        let resp = client
+            .get_institution_by_id(
+                "ins_12",
+                &[CountryCode::US],
+                Some(GetInstitutionByIdOptions {
+                    include_status: true,
+                    include_auth_metadata: true,
+                    ..Default::default()
+                }),
+            )
+            .await
+            .unwrap();
+        assert!(resp.institution.status.is_some());
+        assert!(resp.institution.auth_metadata.is_some());
+
         let resp = client.get_institution_by_id("ins_12", &[], None).await;
-        assert_eq!(resp.is_err(), true);
+        assert!(resp.is_err());
     }
 }
@@ -1,16 +1,20 @@
 use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 
 use crate::accounts::Account;
 use crate::client::Client;
 use crate::errors::Result;
 use crate::holdings::Security;
 use crate::item::Item;
+use crate::transactions::validate_date_range;
+use crate::types::Money;
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct InvestmentTransaction {
     /// The ID of the Investment transaction, unique across all Plaid transactions. Like all Plaid identifiers, the investment_transaction_id is case sensitive.
     pub investment_transaction_id: String,
+    /// For a cancel transaction, the investment_transaction_id of the transaction it cancels.
     pub cancel_transaction_id: Option<String>,
     /// The account_id of the account against which this transaction posted.
     pub account_id: String,
@@ -23,12 +27,12 @@ pub struct InvestmentTransaction {
     /// The number of units of the security involved in this transactions
     pub quantity: f64,
     /// The complete value of the transaction.
-    pub amount: f64,
+    pub amount: Money,
     /// The price of the security at which this transaction occurred.
-    pub price: f64,
+    pub price: Money,
     /// The combined value of all fees applied to this transaction
-    pub fees: Option<f64>,
-    /// Possible values: buy, sell, cancel, cash, fee, transfer
+    pub fees: Option<Money>,
+    /// Possible values: buy, sell, cancel, cash, fee, transfer. See `transaction_type` for the typed value.
     pub r#type: String,
     /// transaction subtype
     pub subtype: String,
@@ -38,6 +42,35 @@ pub struct InvestmentTransaction {
     pub unofficial_currency_code: Option<String>,
 }
 
+impl InvestmentTransaction {
+    /// Returns the transaction type as an [`InvestmentTransactionType`].
+    pub fn transaction_type(&self) -> InvestmentTransactionType {
+        InvestmentTransactionType::from(&self.r#type[..])
+    }
+}
+
+string_enum! {
+    /// The type of an investment transaction.
+    pub enum InvestmentTransactionType {
+        /// Buying an investment.
+        Buy => "buy",
+        /// Selling an investment.
+        Sell => "sell",
+        /// A cancellation of a pending transaction.
+        Cancel => "cancel",
+        /// Activity that modifies a cash position.
+        Cash => "cash",
+        /// A fee on the account.
+        Fee => "fee",
+        /// Activity that modifies a position, but not through buy/sell activity, e.g. options exercise or portfolio transfer.
+        Transfer => "transfer",
+        _ => Other,
+    }
+}
+
+/// The page size used when paginating through /investments/transactions/get.
+const INVESTMENT_TRANSACTIONS_PAGE_SIZE: i32 = 500;
+
 #[derive(Serialize)]
 struct GetInvestmentTransactionsRequest<'a> {
     client_id: &'a str,
@@ -49,7 +82,7 @@ struct GetInvestmentTransactionsRequest<'a> {
     options: Option<GetInvestmentTransactionsOptions<'a>>,
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Debug, Clone, Default)]
 pub struct GetInvestmentTransactionsOptions<'a> {
     /// A list of account_ids to retrieve for the Item
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -62,17 +95,7 @@ pub struct GetInvestmentTransactionsOptions<'a> {
     pub offset: Option<i32>,
 }
 
-impl Default for GetInvestmentTransactionsOptions<'_> {
-    fn default() -> Self {
-        Self {
-            account_ids: None,
-            count: None,
-            offset: None,
-        }
-    }
-}
-
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub struct GetInvestmentTransactionsResponse {
     /// A unique identifier for the request, which can be used for troubleshooting. This identifier, like all Plaid identifiers, is case sensitive.
     pub request_id: String,
@@ -88,6 +111,36 @@ pub struct GetInvestmentTransactionsResponse {
     pub item: Item,
 }
 
+impl GetInvestmentTransactionsResponse {
+    /// Returns the transactions that remain in effect once cancellations are netted out.
+    ///
+    /// Every cancel transaction is paired with the transaction named by its cancel_transaction_id, and both are left out. Cancel transactions whose original is not part of this response are kept. The remaining transactions keep their order.
+    pub fn resolve_cancellations(&self) -> Vec<&InvestmentTransaction> {
+        let ids: HashSet<&str> = self
+            .investment_transactions
+            .iter()
+            .map(|t| t.investment_transaction_id.as_str())
+            .collect();
+        let mut cancelled = HashSet::new();
+        for transaction in &self.investment_transactions {
+            if transaction.transaction_type() != InvestmentTransactionType::Cancel {
+                continue;
+            }
+            if let Some(original_id) = transaction.cancel_transaction_id.as_deref() {
+                if ids.contains(original_id) && original_id != transaction.investment_transaction_id
+                {
+                    cancelled.insert(original_id);
+                    cancelled.insert(transaction.investment_transaction_id.as_str());
+                }
+            }
+        }
+        self.investment_transactions
+            .iter()
+            .filter(|t| !cancelled.contains(t.investment_transaction_id.as_str()))
+            .collect()
+    }
+}
+
 impl Client {
     /// Get investment transactions.
     ///
@@ -108,11 +161,12 @@ impl Client {
         end_date: NaiveDate,
         options: Option<GetInvestmentTransactionsOptions<'a>>,
     ) -> Result<GetInvestmentTransactionsResponse> {
+        validate_date_range(start_date, end_date)?;
         self.send_request(
-            "investment/transactions/get",
+            "investments/transactions/get",
             &GetInvestmentTransactionsRequest {
-                client_id: &self.client_id,
-                secret: &self.secret,
+                client_id: self.client_id(),
+                secret: self.secret(),
                 access_token,
                 start_date,
                 end_date,
@@ -121,6 +175,54 @@ impl Client {
         )
         .await
     }
+
+    /// Get all investment transactions in a date range.
+    ///
+    /// Repeatedly calls /investments/transactions/get, paging through the results until every investment transaction in the date range has been fetched. The returned response contains the investment transactions from all pages and the securities from all pages, deduplicated by security_id, along with the accounts and Item from the last page.
+    ///
+    /// * `access_token` - The access token associated with the Item data is being requested for.
+    /// * `start_date` - The earliest date for which to fetch transaction history.
+    /// * `end_date` - The most recent date for which to fetch transaction history.
+    /// * `account_ids` - An optional list of account_ids to retrieve for the Item.
+    pub async fn get_all_investment_transactions(
+        &self,
+        access_token: &str,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+        account_ids: Option<&[&str]>,
+    ) -> Result<GetInvestmentTransactionsResponse> {
+        let mut investment_transactions = Vec::new();
+        let mut securities = Vec::new();
+        let mut security_ids = HashSet::new();
+        loop {
+            let mut resp = self
+                .get_investment_transactions(
+                    access_token,
+                    start_date,
+                    end_date,
+                    Some(GetInvestmentTransactionsOptions {
+                        account_ids,
+                        count: Some(INVESTMENT_TRANSACTIONS_PAGE_SIZE),
+                        offset: Some(investment_transactions.len() as i32),
+                    }),
+                )
+                .await?;
+            let page_len = resp.investment_transactions.len();
+            investment_transactions.append(&mut resp.investment_transactions);
+            for security in resp.securities.drain(..) {
+                if security_ids.insert(security.security_id.clone()) {
+                    securities.push(security);
+                }
+            }
+            if page_len == 0
+                || investment_transactions.len() as i32 >= resp.total_investment_transactions
+            {
+                resp.investment_transactions = investment_transactions;
+                resp.securities = securities;
+                return Ok(resp);
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -128,16 +230,147 @@ mod tests {
     use chrono::Utc;
     use std::ops::Sub;
 
-    use crate::client::tests::{get_test_client, SANDBOX_INSTITUTION};
-    use crate::errors::Error;
+    use super::*;
+    use crate::client::tests::{get_mock_client, get_test_client, SANDBOX_INSTITUTION};
+    use crate::errors::{Error, PlaidErrorCode};
+    use crate::fixtures::security_fixture;
+    use crate::types::Product;
+
+    #[test]
+    fn test_resolve_cancellations() {
+        let transaction = |id: &str, r#type: &str, cancel_transaction_id: Option<&str>| {
+            serde_json::json!({
+                "investment_transaction_id": id,
+                "cancel_transaction_id": cancel_transaction_id,
+                "account_id": "rz99ex9ZQotvnjXdgQLEsR81e3ArPgulVWjGj",
+                "security_id": "NDVQrXQoqzt5v3bAe8qRt4A7mK7wvZCLEBBJk",
+                "date": "2020-05-28",
+                "name": "BUY Matthews Pacific Tiger Fund Insti Class",
+                "quantity": 0.5,
+                "amount": 25,
+                "price": 50,
+                "fees": 0,
+                "type": r#type,
+                "subtype": r#type,
+                "iso_currency_code": "USD",
+                "unofficial_currency_code": null
+            })
+        };
+        let resp: GetInvestmentTransactionsResponse = serde_json::from_value(serde_json::json!({
+            "request_id": "iv4q3ZlytOOthkv",
+            "accounts": [],
+            "securities": [],
+            "investment_transactions": [
+                transaction("buy_1", "buy", None),
+                transaction("cancel_1", "cancel", Some("buy_1")),
+                transaction("buy_2", "buy", None),
+                transaction("cancel_2", "cancel", Some("outside_range")),
+                transaction("fee_1", "fee", None),
+            ],
+            "total_investment_transactions": 5,
+            "item": {
+                "item_id": "Ed6bjNrDLJfGvZWwnkQlfxwoNz54B5C97ejBr",
+                "update_type": "background"
+            }
+        }))
+        .unwrap();
+        assert_eq!(
+            resp.investment_transactions[1].transaction_type(),
+            InvestmentTransactionType::Cancel
+        );
+        let effective: Vec<&str> = resp
+            .resolve_cancellations()
+            .iter()
+            .map(|t| t.investment_transaction_id.as_str())
+            .collect();
+        assert_eq!(effective, vec!["buy_2", "cancel_2", "fee_1"]);
+    }
+
+    #[tokio::test]
+    async fn test_get_all_investment_transactions() {
+        let transaction = |id: &str, security_id: &str| {
+            serde_json::json!({
+                "investment_transaction_id": id,
+                "account_id": "rz99ex9ZQotvnjXdgQLEsR81e3ArPgulVWjGj",
+                "security_id": security_id,
+                "date": "2020-05-28",
+                "name": "BUY Matthews Pacific Tiger Fund Insti Class",
+                "quantity": 0.5,
+                "amount": 25,
+                "price": 50,
+                "fees": 0,
+                "type": "buy",
+                "subtype": "buy",
+                "iso_currency_code": "USD",
+                "unofficial_currency_code": null
+            })
+        };
+        let page = |transactions: Vec<serde_json::Value>, securities: Vec<serde_json::Value>| {
+            serde_json::json!({
+                "request_id": "iv4q3ZlytOOthkv",
+                "accounts": [],
+                "securities": securities,
+                "investment_transactions": transactions,
+                "total_investment_transactions": 3,
+                "item": {
+                    "item_id": "Ed6bjNrDLJfGvZWwnkQlfxwoNz54B5C97ejBr",
+                    "update_type": "background"
+                }
+            })
+        };
+        let (client, transport) = get_mock_client();
+        transport.push_json(
+            "investments/transactions/get",
+            reqwest::StatusCode::OK,
+            page(
+                vec![transaction("txn_1", "sec_1"), transaction("txn_2", "sec_1")],
+                vec![security_fixture("sec_1")],
+            ),
+        );
+        transport.push_json(
+            "investments/transactions/get",
+            reqwest::StatusCode::OK,
+            page(
+                vec![transaction("txn_3", "sec_2")],
+                vec![security_fixture("sec_1"), security_fixture("sec_2")],
+            ),
+        );
+
+        let resp = client
+            .get_all_investment_transactions(
+                "access-sandbox-123",
+                NaiveDate::from_ymd_opt(2020, 1, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2020, 12, 31).unwrap(),
+                None,
+            )
+            .await
+            .unwrap();
+        let ids: Vec<&str> = resp
+            .investment_transactions
+            .iter()
+            .map(|t| t.investment_transaction_id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["txn_1", "txn_2", "txn_3"]);
+        let security_ids: Vec<&str> = resp
+            .securities
+            .iter()
+            .map(|s| s.security_id.as_str())
+            .collect();
+        assert_eq!(security_ids, vec!["sec_1", "sec_2"]);
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 2);
+        let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+        assert_eq!(body["options"]["offset"], 0);
+        let body: serde_json::Value = serde_json::from_slice(&requests[1].body).unwrap();
+        assert_eq!(body["options"]["offset"], 2);
+    }
 
-    // The following test fails because plaid responds back with NOT_FOUND. Needs debugging.
-    #[ignore]
     #[tokio::test]
     async fn test_get_investment_transactions() {
         let client = get_test_client();
         let sandbox_resp = client
-            .create_sandbox_public_token(SANDBOX_INSTITUTION, &["investments"])
+            .create_sandbox_public_token(SANDBOX_INSTITUTION, &[Product::Investments], None)
             .await
             .unwrap();
         let token_resp = client
@@ -149,13 +382,14 @@ mod tests {
         let mut resp = client
             .get_investment_transactions(&token_resp.access_token, start_date, end_date, None)
             .await;
-        while resp.is_err() {
-            let err = resp.unwrap_err();
-            if let Error::Plaid(err) = err {
-                assert_eq!(err.error_code, "PRODUCT_NOT_READY");
-            } else {
-                assert!(false);
+        let mut attempts = 1;
+        while let Err(err) = &resp {
+            match err {
+                Error::Plaid(plaid_err)
+                    if plaid_err.code() == PlaidErrorCode::ProductNotReady && attempts < 20 => {}
+                _ => panic!("unexpected error after {} attempts: {}", attempts, err),
             }
+            attempts += 1;
             tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
             resp = client
                 .get_investment_transactions(&token_resp.access_token, start_date, end_date, None)
@@ -168,5 +402,18 @@ mod tests {
             assert_ne!(investment_transaction.subtype.len(), 0);
         }
         assert_ne!(resp.securities.len(), 0);
+
+        let all_resp = client
+            .get_all_investment_transactions(&token_resp.access_token, start_date, end_date, None)
+            .await
+            .unwrap();
+        assert_eq!(
+            all_resp.investment_transactions.len() as i32,
+            all_resp.total_investment_transactions
+        );
+        let mut security_ids: Vec<_> = all_resp.securities.iter().map(|s| &s.security_id).collect();
+        security_ids.sort();
+        security_ids.dedup();
+        assert_eq!(security_ids.len(), all_resp.securities.len());
     }
 }
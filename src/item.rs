@@ -1,11 +1,13 @@
 use crate::client::Client;
-use crate::errors::{ErrorResponse, Result};
+use crate::errors::{Error, ItemError, PlaidErrorCode, Result};
+use crate::types::Product;
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
+use reqwest::Url;
 use serde::{Deserialize, Serialize};
 
 /// Metadata about the Item.
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Item {
     /// The Plaid Item ID.
     pub item_id: String,
@@ -14,18 +16,50 @@ pub struct Item {
     /// The URL registered to receive webhooks for the Item.
     pub webhook: Option<String>,
     /// We use standard HTTP response codes for success and failure notifications, and our errors are further classified by error_type.
-    pub error: Option<ErrorResponse>,
+    pub error: Option<ItemError>,
     /// A list of products available for the Item that have not yet been accessed.
+    #[serde(default)]
     pub available_products: Vec<String>,
     /// A list of products that have been billed for the Item. Note - billed_products is populated in all environments but only requests in Production are billed.
+    #[serde(default)]
     pub billed_products: Vec<String>,
     /// The RFC 3339 timestamp after which the consent provided by the end user will expire.
     pub consent_expiration_time: Option<DateTime<Utc>>,
     /// Indicates whether an Item requires user interaction to be updated, which can be the case for Items with some forms of two-factor authentication.
-    pub update_type: String,
+    pub update_type: ItemUpdateType,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+string_enum! {
+    /// Whether an Item can be updated in the background or requires the user to be present.
+    pub enum ItemUpdateType {
+        /// The Item can be updated in the background.
+        Background => "background",
+        /// The Item requires user interaction to be updated, e.g. because of two-factor authentication.
+        UserPresentRequired => "user_present_required",
+        _ => Other,
+    }
+}
+
+impl Item {
+    /// Returns true if the Item is in the ITEM_LOGIN_REQUIRED state and must go through Link's update mode.
+    pub fn requires_login(&self) -> bool {
+        matches!(&self.error, Some(err) if err.code() == PlaidErrorCode::ItemLoginRequired)
+    }
+
+    /// Returns true if the end user's consent has already expired. Items without a consent expiration time never expire.
+    pub fn consent_expired(&self) -> bool {
+        self.consent_expires_within(Duration::zero())
+    }
+
+    /// Returns true if the end user's consent expires within the given duration from now, or has already expired. Use this to re-prompt users through Link's update mode before their consent lapses. Items without a consent expiration time never expire.
+    ///
+    /// * `window` - How far ahead to look for an expiration.
+    pub fn consent_expires_within(&self, window: Duration) -> bool {
+        matches!(self.consent_expiration_time, Some(expiration) if expiration <= Utc::now() + window)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ItemStatus {
     /// Information about the last successful and failed investments update for the Item.
     pub investments: Option<ProductStatus>,
@@ -35,7 +69,45 @@ pub struct ItemStatus {
     pub last_webhook: Option<WebhookStatus>,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+impl ItemStatus {
+    /// Returns the update status of a product. Only transactions and investments report an update status.
+    fn product_status(&self, product: &Product) -> Option<&ProductStatus> {
+        match product {
+            Product::Transactions => self.transactions.as_ref(),
+            Product::Investments => self.investments.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the product has not been successfully updated within `threshold` of now, including when it has never been successfully updated or reports no update status.
+    ///
+    /// * `product` - The product to check, either transactions or investments.
+    /// * `threshold` - The maximum age of the last successful update.
+    pub fn is_stale(&self, product: &Product, threshold: Duration) -> bool {
+        match self
+            .product_status(product)
+            .and_then(|status| status.last_successful_update)
+        {
+            Some(last_successful_update) => last_successful_update < Utc::now() - threshold,
+            None => true,
+        }
+    }
+
+    /// Returns true if the most recent update attempt for the product failed, i.e. its last failed update is later than its last successful update.
+    ///
+    /// * `product` - The product to check, either transactions or investments.
+    pub fn has_recent_failure(&self, product: &Product) -> bool {
+        match self.product_status(product) {
+            Some(ProductStatus {
+                last_failed_update: Some(failed),
+                last_successful_update,
+            }) => !matches!(last_successful_update, Some(successful) if successful >= failed),
+            _ => false,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ProductStatus {
     /// ISO 8601 timestamp of the last successful transactions update for the Item. The status will update each time Plaid successfully connects with the institution, regardless of whether any new data is available in the update.
     pub last_successful_update: Option<DateTime<Utc>>,
@@ -43,7 +115,7 @@ pub struct ProductStatus {
     pub last_failed_update: Option<DateTime<Utc>>,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct WebhookStatus {
     /// ISO 8601 timestamp of when the webhook was fired.
     pub sent_at: Option<DateTime<Utc>>,
@@ -58,7 +130,7 @@ struct GetItemRequest<'a> {
     access_token: &'a str,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct GetItemResponse {
     /// A unique identifier for the request, which can be used for troubleshooting. This identifier, like all Plaid identifiers, is case sensitive.
     pub request_id: String,
@@ -77,7 +149,7 @@ struct RemoveItemRequest<'a> {
     access_token: &'a str,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct RemoveItemResponse {
     /// A unique identifier for the request, which can be used for troubleshooting. This identifier, like all Plaid identifiers, is case sensitive.
     pub request_id: String,
@@ -91,7 +163,7 @@ struct UpdateItemWebhookRequest<'a> {
     webhook: &'a str,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct UpdateItemWebhookResponse {
     /// A unique identifier for the request, which can be used for troubleshooting. This identifier, like all Plaid identifiers, is case sensitive.
     pub request_id: String,
@@ -106,7 +178,7 @@ struct InvalidateAccessTokenRequest<'a> {
     access_token: &'a str,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct InvalidateAccessTokenResponse {
     pub request_id: String,
     pub new_access_token: String,
@@ -119,7 +191,7 @@ struct CreatePublicTokenRequest<'a> {
     access_token: &'a str,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct CreatePublicTokenResponse {
     pub request_id: String,
     pub public_token: String,
@@ -132,13 +204,57 @@ struct ExchangePublicTokenRequest<'a> {
     public_token: &'a str,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ExchangePublicTokenResponse {
     pub request_id: String,
     pub access_token: String,
     pub item_id: String,
 }
 
+#[derive(Serialize, Debug, Clone)]
+pub struct ImportItemUserAuth<'a> {
+    /// Opaque user identifier
+    pub user_id: &'a str,
+    /// Authentication token for the user at the institution
+    pub auth_token: &'a str,
+}
+
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct ImportItemOptions<'a> {
+    /// Specifies a webhook URL to associate with an Item. Plaid fires a webhook if credentials fail.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webhook: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+struct ImportItemRequest<'a> {
+    client_id: &'a str,
+    secret: &'a str,
+    products: &'a [Product],
+    user_auth: ImportItemUserAuth<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    options: Option<ImportItemOptions<'a>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ImportItemResponse {
+    /// A unique identifier for the request, which can be used for troubleshooting. This identifier, like all Plaid identifiers, is case sensitive.
+    pub request_id: String,
+    /// The access token associated with the imported Item.
+    pub access_token: String,
+}
+
+/// Checks that a webhook is an absolute HTTPS URL before it is sent to Plaid.
+pub(crate) fn validate_webhook_url(webhook: &str) -> Result<()> {
+    match Url::parse(webhook) {
+        Ok(url) if url.scheme() == "https" && url.has_host() => Ok(()),
+        _ => Err(Error::InvalidRequest(format!(
+            "webhook {:?} is not a valid HTTPS URL",
+            webhook
+        ))),
+    }
+}
+
 impl Client {
     /// Retrieve an Item.
     ///
@@ -149,8 +265,8 @@ impl Client {
         self.send_request(
             "item/get",
             &GetItemRequest {
-                client_id: &self.client_id,
-                secret: &self.secret,
+                client_id: self.client_id(),
+                secret: self.secret(),
                 access_token,
             },
         )
@@ -166,30 +282,48 @@ impl Client {
         self.send_request(
             "item/remove",
             &RemoveItemRequest {
-                client_id: &self.client_id,
-                secret: &self.secret,
+                client_id: self.client_id(),
+                secret: self.secret(),
                 access_token,
             },
         )
         .await
     }
 
+    /// Remove an Item, taking ownership of its access token.
+    ///
+    /// Behaves like `remove_item`. With the `zeroize` feature enabled, the memory of `access_token` is overwritten with zeros once the request completes, whether or not it succeeded; without it, the token is simply dropped.
+    ///
+    /// Only the `String` passed in is zeroized. Copies of the token in the serialized request body and in reqwest's buffers are not.
+    ///
+    /// * `access_token` - The access token associated with the Item data is being requested for.
+    pub async fn remove_item_consuming(&self, access_token: String) -> Result<RemoveItemResponse> {
+        let resp = self.remove_item(&access_token).await;
+        #[cfg(feature = "zeroize")]
+        {
+            let mut access_token = access_token;
+            zeroize::Zeroize::zeroize(&mut access_token);
+        }
+        resp
+    }
+
     /// Update Webhook URL.
     ///
     /// The POST /item/webhook/update allows you to update the webhook URL associated with an Item. This request triggers a WEBHOOK_UPDATE_ACKNOWLEDGED webhook to the newly specified webhook URL.
     ///
     /// * `access_token` - The access token associated with the Item data is being requested for.
-    /// * `webhook` - The new webhook URL to associate with the Item.
+    /// * `webhook` - The new webhook URL to associate with the Item. Must be an HTTPS URL.
     pub async fn update_item_webhook(
         &self,
         access_token: &str,
         webhook: &str,
     ) -> Result<UpdateItemWebhookResponse> {
+        validate_webhook_url(webhook)?;
         self.send_request(
             "item/webhook/update",
             &UpdateItemWebhookRequest {
-                client_id: &self.client_id,
-                secret: &self.secret,
+                client_id: self.client_id(),
+                secret: self.secret(),
                 access_token,
                 webhook,
             },
@@ -211,8 +345,8 @@ impl Client {
         self.send_request(
             "item/access_token/invalidate",
             &InvalidateAccessTokenRequest {
-                client_id: &self.client_id,
-                secret: &self.secret,
+                client_id: self.client_id(),
+                secret: self.secret(),
                 access_token,
             },
         )
@@ -231,14 +365,40 @@ impl Client {
         self.send_request(
             "item/public_token/create",
             &CreatePublicTokenRequest {
-                client_id: &self.client_id,
-                secret: &self.secret,
+                client_id: self.client_id(),
+                secret: self.secret(),
                 access_token,
             },
         )
         .await
     }
 
+    /// Import Item.
+    ///
+    /// /item/import creates an Item via your Plaid Exchange Integration and returns an access_token. As part of an /item/import request, you will include a User ID (user_auth.user_id) and Authentication Token (user_auth.auth_token) that enable data aggregation through your Plaid Exchange API endpoints. The returned access_token can be used as the target_access_token of /deposit_switch/create.
+    ///
+    /// * `products` - Array of product strings.
+    /// * `user_auth` - Object of user ID and auth token pair, permitting Plaid to aggregate a user's accounts.
+    /// * `options` - An optional object to configure /item/import request.
+    pub async fn import_item<'a>(
+        &self,
+        products: &[Product],
+        user_auth: ImportItemUserAuth<'a>,
+        options: Option<ImportItemOptions<'a>>,
+    ) -> Result<ImportItemResponse> {
+        self.send_request(
+            "item/import",
+            &ImportItemRequest {
+                client_id: self.client_id(),
+                secret: self.secret(),
+                products,
+                user_auth,
+                options,
+            },
+        )
+        .await
+    }
+
     /// Exchange public token for an access token.
     ///
     /// Exchange a Link public_token for an API access_token. Link hands off the public_token client-side via the onSuccess callback once a user has successfully created an Item. The public_token is ephemeral and expires after 30 minutes.
@@ -253,8 +413,8 @@ impl Client {
         self.send_request(
             "item/public_token/exchange",
             &ExchangePublicTokenRequest {
-                client_id: &self.client_id,
-                secret: &self.secret,
+                client_id: self.client_id(),
+                secret: self.secret(),
                 public_token,
             },
         )
@@ -264,13 +424,125 @@ impl Client {
 
 #[cfg(test)]
 mod tests {
-    use crate::client::tests::{get_test_client, SANDBOX_INSTITUTION, TEST_PRODUCTS};
+    use super::*;
+    use crate::client::tests::{
+        get_mock_client, get_test_client, SANDBOX_INSTITUTION, TEST_PRODUCTS,
+    };
+
+    #[test]
+    fn test_requires_login() {
+        let mut item: Item = serde_json::from_value(serde_json::json!({
+            "item_id": "Ed6bjNrDLJfGvZWwnkQlfxwoNz54B5C97ejBr",
+            "institution_id": "ins_109508",
+            "webhook": null,
+            "error": {
+                "error_type": "ITEM_ERROR",
+                "error_code": "ITEM_LOGIN_REQUIRED",
+                "error_message": "the login details of this item have changed (credentials, MFA, or required user action) and a user login is required to update this information",
+                "display_message": null
+            },
+            "available_products": [],
+            "billed_products": ["transactions"],
+            "consent_expiration_time": null,
+            "update_type": "background"
+        }))
+        .unwrap();
+        assert!(item.requires_login());
+        assert_eq!(item.update_type, ItemUpdateType::Background);
+        item.error = None;
+        assert!(!item.requires_login());
+    }
+
+    #[tokio::test]
+    async fn test_remove_item_consuming() {
+        let (client, transport) = get_mock_client();
+        transport.push_json(
+            "item/remove",
+            reqwest::StatusCode::OK,
+            serde_json::json!({"request_id": "m8MDnv9okwxFNBV"}),
+        );
+        let resp = client
+            .remove_item_consuming("access-sandbox-123".to_string())
+            .await
+            .unwrap();
+        assert_eq!(resp.request_id, "m8MDnv9okwxFNBV");
+        let body: serde_json::Value =
+            serde_json::from_slice(&transport.requests()[0].body).unwrap();
+        assert_eq!(body["access_token"], "access-sandbox-123");
+    }
+
+    #[test]
+    fn test_consent_expiration() {
+        let mut item: Item = serde_json::from_value(serde_json::json!({
+            "item_id": "Ed6bjNrDLJfGvZWwnkQlfxwoNz54B5C97ejBr",
+            "update_type": "background"
+        }))
+        .unwrap();
+        assert!(!item.consent_expired());
+        assert!(!item.consent_expires_within(Duration::days(365)));
+
+        item.consent_expiration_time = Some(Utc::now() + Duration::days(5));
+        assert!(!item.consent_expired());
+        assert!(!item.consent_expires_within(Duration::days(4)));
+        assert!(item.consent_expires_within(Duration::days(7)));
+
+        item.consent_expiration_time = Some(Utc::now() - Duration::minutes(1));
+        assert!(item.consent_expired());
+        assert!(item.consent_expires_within(Duration::zero()));
+    }
+
+    #[test]
+    fn test_minimal_item() {
+        let item: Item = serde_json::from_value(serde_json::json!({
+            "item_id": "Ed6bjNrDLJfGvZWwnkQlfxwoNz54B5C97ejBr",
+            "update_type": "background"
+        }))
+        .unwrap();
+        assert!(item.institution_id.is_none());
+        assert!(item.available_products.is_empty());
+        assert!(item.billed_products.is_empty());
+    }
+
+    #[test]
+    fn test_item_status_health() {
+        let now = Utc::now();
+        let status = ItemStatus {
+            transactions: Some(ProductStatus {
+                last_successful_update: Some(now - Duration::hours(1)),
+                last_failed_update: Some(now - Duration::minutes(10)),
+            }),
+            investments: Some(ProductStatus {
+                last_successful_update: Some(now - Duration::days(3)),
+                last_failed_update: Some(now - Duration::days(4)),
+            }),
+            last_webhook: None,
+        };
+        assert!(!status.is_stale(&Product::Transactions, Duration::days(1)));
+        assert!(status.is_stale(&Product::Investments, Duration::days(1)));
+        assert!(status.is_stale(&Product::Auth, Duration::days(1)));
+        assert!(status.has_recent_failure(&Product::Transactions));
+        assert!(!status.has_recent_failure(&Product::Investments));
+        assert!(!status.has_recent_failure(&Product::Auth));
+    }
+
+    #[tokio::test]
+    async fn test_update_item_webhook_validation() {
+        let (client, transport) = get_mock_client();
+        for webhook in ["", "http://example.com/webhook", "not a url", "https:"] {
+            let err = client
+                .update_item_webhook("access-sandbox-123", webhook)
+                .await
+                .unwrap_err();
+            assert!(matches!(err, Error::InvalidRequest(_)), "{}", webhook);
+        }
+        assert!(transport.requests().is_empty());
+    }
 
     #[tokio::test]
     async fn test_get_item() {
         let client = get_test_client();
         let sandbox_resp = client
-            .create_sandbox_public_token(SANDBOX_INSTITUTION, TEST_PRODUCTS)
+            .create_sandbox_public_token(SANDBOX_INSTITUTION, TEST_PRODUCTS, None)
             .await
             .unwrap();
         let token_resp = client
@@ -5,52 +5,100 @@ use crate::accounts::Account;
 use crate::client::Client;
 use crate::errors::Result;
 use crate::item::Item;
+use crate::types::Money;
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct CreditLiability {
     /// The ID of the account that this liability belongs to.
     pub account_id: Option<String>,
     /// The various interest rates that apply to the account.
+    #[serde(default)]
     pub aprs: Vec<APR>,
     /// true if a payment is currently overdue. Availability for this field is limited.
     pub is_overdue: Option<bool>,
     /// The amount of the last payment.
-    pub last_payment_amount: f64,
+    pub last_payment_amount: Option<Money>,
     /// The date of the last payment. Dates are returned in an ISO 8601 format (YYYY-MM-DD). Availability for this field is limited.
     pub last_payment_date: Option<NaiveDate>,
     /// The outstanding balance on the last statement. Availability for this field is limited.
-    pub last_statement_balance: f64,
+    pub last_statement_balance: Option<Money>,
     /// The date of the last statement. Dates are returned in an ISO 8601 format (YYYY-MM-DD).
-    pub last_statement_issue_date: NaiveDate,
+    pub last_statement_issue_date: Option<NaiveDate>,
     /// The minimum payment due for the next billing cycle.
-    pub minimum_payment_amount: f64,
+    pub minimum_payment_amount: Option<Money>,
     /// The due date for the next payment. The due date is null if a payment is not expected. Dates are returned in an ISO 8601 format (YYYY-MM-DD).
     pub next_payment_due_date: Option<NaiveDate>,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+impl CreditLiability {
+    /// Returns the APR applied to purchases, if the institution reports one.
+    pub fn purchase_apr(&self) -> Option<&APR> {
+        self.aprs
+            .iter()
+            .find(|apr| apr.apr_type == AprType::PurchaseApr)
+    }
+
+    /// Returns the fraction of the credit limit in use, i.e. the current balance divided by the limit.
+    ///
+    /// Returns None if the account is not the one this liability belongs to, or if it has no positive credit limit.
+    ///
+    /// * `account` - The credit account this liability belongs to, as returned alongside it.
+    pub fn utilization(&self, account: &Account) -> Option<Money> {
+        if self.account_id.as_deref() != Some(account.account_id.as_str()) {
+            return None;
+        }
+        match account.balances.limit {
+            Some(limit) if limit > Money::default() => Some(account.balances.current / limit),
+            _ => None,
+        }
+    }
+}
+
+string_enum! {
+    /// The type of balance to which an APR applies.
+    pub enum AprType {
+        /// The APR applied to balance transfers.
+        BalanceTransferApr => "balance_transfer_apr",
+        /// The APR applied to cash advances.
+        CashApr => "cash_apr",
+        /// The APR applied to purchases.
+        PurchaseApr => "purchase_apr",
+        /// A special, often promotional, APR.
+        Special => "special",
+        _ => Other,
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct APR {
     /// Annual Percentage Rate applied.
     pub apr_percentage: f64,
     /// The type of balance to which the APR applies.
-    /// Possible values: balance_transfer_apr, cash_apr, purchase_apr, special
-    pub apr_type: String,
+    pub apr_type: AprType,
     /// Amount of money that is subjected to the APR if a balance was carried beyond payment due date. How it is calculated can vary by card issuer. It is often calculated as an average daily balance.
-    pub balance_subject_to_api: Option<f64>,
+    pub balance_subject_to_apr: Option<Money>,
     /// Amount of money charged due to interest from last statement.
-    pub interest_charge_amount: Option<f64>,
+    pub interest_charge_amount: Option<Money>,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+impl APR {
+    /// The misspelled name this crate used for `balance_subject_to_apr`, which was never populated.
+    #[deprecated(note = "use the balance_subject_to_apr field")]
+    pub fn balance_subject_to_api(&self) -> Option<Money> {
+        self.balance_subject_to_apr
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct MortgageLiability {
     /// The ID of the account that this liability belongs to.
     pub account_id: Option<String>,
     /// The account number of the loan.
     pub account_number: String,
     /// The current outstanding amount charged for late payment.
-    pub current_late_fee: Option<f64>,
+    pub current_late_fee: Option<Money>,
     /// Total amount held in escrow to pay taxes and insurance on behalf of the borrower.
-    pub escrow_balance: Option<f64>,
+    pub escrow_balance: Option<Money>,
     /// Indicates whether the borrower has private mortgage insurance in effect.
     pub has_pmi: Option<bool>,
     /// Indicates whether the borrower will pay a penalty for early payoff of mortgage.
@@ -58,7 +106,7 @@ pub struct MortgageLiability {
     /// Object containing metadata about the interest rate for the mortgage.
     pub interest_rate: MortgageInterestRate,
     /// The amount of the last payment.
-    pub last_payment_amount: Option<f64>,
+    pub last_payment_amount: Option<Money>,
     /// The date of the last payment. Dates are returned in an ISO 8601 format (YYYY-MM-DD).
     pub last_payment_date: Option<NaiveDate>,
     /// Description of the type of loan, for example conventional, fixed, or variable. This field is provided directly from the loan servicer and does not have an enumerated set of possible values.
@@ -68,24 +116,24 @@ pub struct MortgageLiability {
     /// Original date on which mortgage is due in full. Dates are returned in an ISO 8601 format (YYYY-MM-DD).
     pub maturity_date: Option<NaiveDate>,
     /// The amount of the next payment.
-    pub next_monthly_payment: Option<f64>,
+    pub next_monthly_payment: Option<Money>,
     /// The due date for the next payment. Dates are returned in an ISO 8601 format (YYYY-MM-DD).
     pub next_payment_due_date: Option<NaiveDate>,
     /// The date on which the loan was initially lent. Dates are returned in an ISO 8601 format (YYYY-MM-DD).
     pub origination_date: Option<NaiveDate>,
     /// The original principal balance of the mortgage.
-    pub origination_principal_amount: Option<f64>,
+    pub origination_principal_amount: Option<Money>,
     /// Amount of loan (principal + interest) past due for payment.
-    pub past_due_amount: Option<f64>,
+    pub past_due_amount: Option<Money>,
     /// Object containing fields describing property address.
     pub property_address: MortgagePropertyAddress,
     /// The year to date (YTD) interest paid.
-    pub ytd_interest_paid: Option<f64>,
+    pub ytd_interest_paid: Option<Money>,
     /// The YTD principal paid.
-    pub ytd_principal_paid: Option<f64>,
+    pub ytd_principal_paid: Option<Money>,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct MortgageInterestRate {
     /// Percentage value (interest rate of current mortgage, not APR) of interest payable on a loan.
     pub percentage: Option<f64>,
@@ -93,7 +141,7 @@ pub struct MortgageInterestRate {
     pub r#type: Option<String>,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct MortgagePropertyAddress {
     /// The city name.
     pub city: Option<String>,
@@ -107,7 +155,7 @@ pub struct MortgagePropertyAddress {
     pub street: Option<String>,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct StudentLoanLiability {
     /// The ID of the account that this liability belongs to.
     pub account_id: Option<String>,
@@ -124,11 +172,11 @@ pub struct StudentLoanLiability {
     /// true if a payment is currently overdue. Availability for this field is limited.
     pub is_overdue: Option<bool>,
     /// The amount of the last payment.
-    pub last_payment_amount: Option<f64>,
+    pub last_payment_amount: Option<Money>,
     /// The date of the last payment. Dates are returned in an ISO 8601 format (YYYY-MM-DD).
     pub last_payment_date: Option<NaiveDate>,
     /// The outstanding balance on the last statement. This field could also be interpreted as the next payment due. Availability for this field is limited.
-    pub last_statement_balance: Option<f64>,
+    pub last_statement_balance: Option<Money>,
     /// The date of the last statement. Dates are returned in an ISO 8601 format (YYYY-MM-DD).
     pub last_statement_issue_date: Option<NaiveDate>,
     /// The type of loan, e.g., "Consolidation Loans".
@@ -136,15 +184,15 @@ pub struct StudentLoanLiability {
     /// An object representing the status of the student loan
     pub loan_status: StudentLoanStatus,
     /// The minimum payment due for the next billing cycle.
-    pub minimum_payment_amount: Option<f64>,
+    pub minimum_payment_amount: Option<Money>,
     /// The due date for the next payment.
     pub next_payment_due_date: Option<NaiveDate>,
     /// The date on which the loan was initially lent. Dates are returned in an ISO 8601 format (YYYY-MM-DD).
     pub origination_date: Option<NaiveDate>,
     /// The original principal balance of the loan.
-    pub origination_principal_amount: Option<f64>,
+    pub origination_principal_amount: Option<Money>,
     /// The total dollar amount of the accrued interest balance. For Sallie Mae ( ins_116944), this amount is included in the current balance of the loan, so this field will return as null.
-    pub origination_interest_amount: Option<f64>,
+    pub origination_interest_amount: Option<Money>,
     /// The relevant account number that should be used to reference this loan for payments. In the majority of cases, payment_reference_number will match account_number, but in some institutions, such as Great Lakes (ins_116861), it will be different.
     pub payment_reference_number: Option<String>,
     /// Information about the student's eligibility in the Public Service Loan Forgiveness program. This is only returned if the institution is Fedloan (ins_116527).
@@ -156,12 +204,12 @@ pub struct StudentLoanLiability {
     /// The address of the student loan servicer. This is generally the remittance address to which payments should be sent.
     pub servicer_address: StudentLoanServicerAddress,
     /// The year to date (YTD) interest paid. Availability for this field is limited.
-    pub ytd_interest_paid: Option<f64>,
+    pub ytd_interest_paid: Option<Money>,
     /// The year to date (YTD) principal paid. Availability for this field is limited.
-    pub ytd_principal_paid: Option<f64>,
+    pub ytd_principal_paid: Option<Money>,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct StudentLoanStatus {
     /// The date until which the loan will be in its current status. Dates are returned in an ISO 8601 format (YYYY-MM-DD).
     pub end_date: Option<NaiveDate>,
@@ -170,7 +218,7 @@ pub struct StudentLoanStatus {
     pub r#type: Option<String>,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct PSLFStatus {
     /// The estimated date borrower will have completed 120 qualifying monthly payments. Returned in ISO 8601 format (YYYY-MM-DD).
     pub estimated_eligibility_date: Option<NaiveDate>,
@@ -180,7 +228,7 @@ pub struct PSLFStatus {
     pub payments_remaining: Option<i64>,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct StudentLoanRepaymentPlan {
     /// The description of the repayment plan as provided by the servicer.
     pub description: Option<String>,
@@ -189,7 +237,7 @@ pub struct StudentLoanRepaymentPlan {
     pub r#type: Option<String>,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct StudentLoanServicerAddress {
     /// The full city name
     pub city: Option<String>,
@@ -205,7 +253,7 @@ pub struct StudentLoanServicerAddress {
     pub street: Option<String>,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Liabilities {
     /// The credit accounts returned. If no credit accounts are returned, credit will not be present in the schema.
     pub credit: Option<Vec<CreditLiability>>,
@@ -215,6 +263,20 @@ pub struct Liabilities {
     pub student: Option<Vec<StudentLoanLiability>>,
 }
 
+impl Liabilities {
+    /// Returns the account_id of every credit, mortgage and student loan liability, in that order.
+    pub fn all_account_ids(&self) -> Vec<&str> {
+        let credit = self.credit.iter().flatten().map(|l| &l.account_id);
+        let mortgage = self.mortgage.iter().flatten().map(|l| &l.account_id);
+        let student = self.student.iter().flatten().map(|l| &l.account_id);
+        credit
+            .chain(mortgage)
+            .chain(student)
+            .filter_map(|account_id| account_id.as_deref())
+            .collect()
+    }
+}
+
 #[derive(Serialize)]
 struct GetLiabilitiesRequest<'a> {
     client_id: &'a str,
@@ -230,7 +292,7 @@ pub struct GetLiabilitiesOptions<'a> {
     pub account_ids: &'a [&'a str],
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct GetLiabilitiesResponse {
     /// A unique identifier for the request, which can be used for troubleshooting. This identifier, like all Plaid identifiers, is case sensitive.
     pub request_id: String,
@@ -259,8 +321,8 @@ impl Client {
         self.send_request(
             "liabilities/get",
             &GetLiabilitiesRequest {
-                client_id: &self.client_id,
-                secret: &self.secret,
+                client_id: self.client_id(),
+                secret: self.secret(),
                 access_token,
                 options,
             },
@@ -273,12 +335,87 @@ impl Client {
 mod tests {
     use super::*;
     use crate::client::tests::{get_test_client, SANDBOX_INSTITUTION};
+    use crate::fixtures::{account_fixture, merge};
+    use crate::types::Product;
+
+    #[test]
+    fn test_purchase_apr_and_utilization() {
+        let liability: CreditLiability = serde_json::from_value(serde_json::json!({
+            "account_id": "dVzbVMLjrxTnLjX4G66XUp5GLklm4oiZy88yK",
+            "aprs": [
+                {"apr_percentage": 27.95, "apr_type": "cash_apr", "balance_subject_to_apr": null, "interest_charge_amount": null},
+                {"apr_percentage": 15.24, "apr_type": "purchase_apr", "balance_subject_to_apr": 1562.32, "interest_charge_amount": 130.22}
+            ],
+            "is_overdue": false,
+            "last_payment_amount": 168.25,
+            "last_payment_date": "2019-05-22",
+            "last_statement_balance": 1708.77,
+            "last_statement_issue_date": "2019-05-28",
+            "minimum_payment_amount": 20,
+            "next_payment_due_date": "2020-05-28"
+        }))
+        .unwrap();
+        assert_eq!(liability.aprs[0].apr_type, AprType::CashApr);
+        assert_eq!(liability.purchase_apr().unwrap().apr_percentage, 15.24);
+        assert!(liability.aprs[0].balance_subject_to_apr.is_none());
+        assert_eq!(
+            liability.purchase_apr().unwrap().balance_subject_to_apr,
+            Some("1562.32".parse().unwrap())
+        );
+
+        let mut card: Account = serde_json::from_value(merge(
+            account_fixture(
+                "dVzbVMLjrxTnLjX4G66XUp5GLklm4oiZy88yK",
+                "credit",
+                Some("credit card"),
+            ),
+            serde_json::json!({"balances": {"available": null, "current": 500, "limit": 2000}}),
+        ))
+        .unwrap();
+        assert_eq!(
+            liability.utilization(&card),
+            Some(Money::from(500) / Money::from(2000))
+        );
+        card.balances.limit = None;
+        assert!(liability.utilization(&card).is_none());
+        card.balances.limit = Some(Money::default());
+        assert!(liability.utilization(&card).is_none());
+        card.balances.limit = Some(Money::from(2000));
+        card.account_id = "BxBXxLj1m4HMXBm9WZZmCWVbPjX16EHwv99vp".to_string();
+        assert!(liability.utilization(&card).is_none());
+    }
+
+    #[test]
+    fn test_all_account_ids() {
+        let liabilities: Liabilities = serde_json::from_value(serde_json::json!({
+            "credit": [{
+                "account_id": "dVzbVMLjrxTnLjX4G66XUp5GLklm4oiZy88yK",
+                "aprs": [],
+                "is_overdue": null,
+                "last_payment_amount": null,
+                "last_payment_date": null,
+                "last_statement_balance": null,
+                "last_statement_issue_date": null,
+                "minimum_payment_amount": null,
+                "next_payment_due_date": null
+            }],
+            "mortgage": null
+        }))
+        .unwrap();
+        assert!(liabilities.credit.as_ref().unwrap()[0]
+            .last_statement_balance
+            .is_none());
+        assert_eq!(
+            liabilities.all_account_ids(),
+            vec!["dVzbVMLjrxTnLjX4G66XUp5GLklm4oiZy88yK"]
+        );
+    }
 
     #[tokio::test]
     async fn test_get_liabilities() {
         let client = get_test_client();
         let sandbox_resp = client
-            .create_sandbox_public_token(SANDBOX_INSTITUTION, &["liabilities"])
+            .create_sandbox_public_token(SANDBOX_INSTITUTION, &[Product::Liabilities], None)
             .await
             .unwrap();
         let token_resp = client
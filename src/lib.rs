@@ -16,13 +16,14 @@
 //! The following example shows you how to connect to Plaid, and retrieve transactions:
 //!
 //! ```ignore
+//! use plaid::types::Product;
 //! use plaid::Client;
 //!
 //! #[tokio::main]
 //! async fn main() {
 //!     // Get a valid sandbox access token. You should substitute `access_token` with your own valid access token.
 //!     let sandbox_resp = client
-//!         .create_sandbox_public_token("ins_109508", &["auth", "identity", "transactions"])
+//!         .create_sandbox_public_token("ins_109508", &[Product::Auth, Product::Identity, Product::Transactions], None)
 //!         .await
 //!         .unwrap();
 //!     let token_resp = client
@@ -48,11 +49,34 @@
 //!
 //! You can run `cargo test` to run the test suite. You must supply sandbox credentials in `PLAID_CLIENT_ID` and `PLAID_SECRET` environment variables, or the tests will fail.
 //!
+//! To test code that uses the client without network access, enable the `mock` feature and create the client with `Client::from_transport` and a `transport::MockTransport` preloaded with responses.
+//!
+//! ## Decimal
+//!
+//! Enable the `decimal` feature to represent amounts as `rust_decimal::Decimal` instead of `f64`. Amounts are deserialized exactly and sent to Plaid as exact JSON numbers. When you serialize a response struct yourself, its amounts are written as strings such as `"72.10"` so that no precision is lost.
+//!
+//! This feature turns on the `arbitrary_precision` feature of `serde_json`. Cargo unifies features, so every crate in your dependency graph that uses `serde_json` is built with it, which changes how they handle numbers inside `serde_json::Value`.
+//!
+//! ## Tracing
+//!
+//! Enable the `tracing` feature to wrap every request in a `plaid.request` span carrying the endpoint path, and to emit an event with the HTTP status, elapsed time and Plaid `request_id` of each response. Request and response bodies are never recorded, so credentials and account data stay out of your logs.
+//!
+//! ## Zeroize
+//!
+//! Enable the `zeroize` feature to have `Client::remove_item_consuming` overwrite the access token it takes ownership of once the Item is removed.
+//!
+
+#[macro_use]
+mod macros;
+#[cfg(test)]
+mod fixtures;
 
 pub mod accounts;
+pub mod asset_report;
 pub mod auth;
 pub mod categories;
 pub mod client;
+pub mod credit;
 pub mod deposit_switch;
 pub mod errors;
 pub mod holdings;
@@ -62,9 +86,15 @@ pub mod investment_transactions;
 pub mod item;
 pub mod liabilities;
 pub mod link_token;
+pub mod payment_initiation;
 pub mod processor;
 pub mod sandbox;
+pub mod signal;
 pub mod transactions;
+pub mod transfer;
+pub mod transport;
+pub mod types;
+pub mod user;
 pub mod webhooks;
 
-pub use client::Client;
+pub use client::{Client, ClientBuilder};
@@ -1,11 +1,13 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
 
+use crate::accounts::AccountSubtype;
 use crate::client::Client;
 use crate::errors::Result;
+use crate::institutions::validate_country_codes;
+use crate::types::{CountryCode, Product};
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Debug, Clone, Default)]
 pub struct LinkTokenUser<'a> {
     /// A unique ID representing the end user.
     pub client_user_id: &'a str,
@@ -32,19 +34,50 @@ pub struct LinkTokenUser<'a> {
     pub date_of_birth: Option<&'a str>,
 }
 
-impl Default for LinkTokenUser<'_> {
-    fn default() -> Self {
-        Self {
-            client_user_id: "",
-            legal_name: None,
-            phone_number: None,
-            phone_number_verified_time: None,
-            email_address: None,
-            email_address_verified_time: None,
-            ssn: None,
-            date_of_birth: None,
-        }
-    }
+/// The account subtypes to show in Link for one account type.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct AccountSubtypeFilter {
+    /// An array of account subtypes to display in Link.
+    pub account_subtypes: Vec<AccountSubtype>,
+}
+
+/// Filters the accounts that will be displayed in Link by account type and subtype.
+///
+/// Account types that are not set are not shown in Link.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct AccountFilters {
+    /// Filters for depository accounts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depository: Option<AccountSubtypeFilter>,
+    /// Filters for credit accounts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub credit: Option<AccountSubtypeFilter>,
+    /// Filters for loan accounts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub loan: Option<AccountSubtypeFilter>,
+    /// Filters for investment accounts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub investment: Option<AccountSubtypeFilter>,
+}
+
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct UpdateConfig {
+    /// If true, enables update mode with Account Select, allowing the user to change which accounts are shared with your app.
+    pub account_selection_enabled: bool,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct PaymentInitiationConfig<'a> {
+    /// The payment_id provided by the /payment_initiation/payment/create endpoint.
+    pub payment_id: &'a str,
+}
+
+/// Information about the institution to pre-select in Link.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct LinkTokenInstitutionData<'a> {
+    /// The routing number of the bank to pre-select in Link.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub routing_number: Option<&'a str>,
 }
 
 #[derive(Debug, Clone)]
@@ -56,20 +89,31 @@ pub struct LinkTokenConfigs<'a> {
     /// The language that Link should be displayed in.
     pub language: &'a str,
     /// Specify an array of Plaid-supported country codes using the ISO-3166-1 alpha-2 country code standard.
-    pub country_codes: &'a [&'a str],
+    pub country_codes: &'a [CountryCode],
     /// List of Plaid product(s) you wish to use.
-    pub products: Option<&'a [&'a str]>,
+    pub products: Option<&'a [Product]>,
     /// The destination URL to which any webhooks should be sent.
     pub webhook: Option<&'a str>,
     /// The access_token associated with the Item to update, used when updating or modifying an existing access_token. Used when launching Link in update mode, when completing the Same-day (manual) Micro-deposit flow, or (optionally) when initializing Link as part of the Payment Initiation (UK and Europe) flow.
     pub access_token: Option<&'a str>,
     /// The name of the Link customization from the Plaid Dashboard to be applied to Link.
     pub link_customization_name: Option<&'a str>,
-    pub account_filters: Option<HashMap<&'a str, HashMap<&'a str, Vec<&'a str>>>>,
+    /// By default, Link will only display account types that are compatible with all products supplied in the products parameter. You can further limit the accounts shown in Link by using account_filters to specify the account subtypes to be shown in Link.
+    pub account_filters: Option<AccountFilters>,
     /// A URI indicating the destination where a user should be forwarded after completing the Link flow
     pub redirect_uri: Option<&'a str>,
     /// The name of your app's Android package.
     pub android_package_name: Option<&'a str>,
+    /// Specifies options for initializing Link for use with the Payment Initiation (Europe) product. This field is required if payment_initiation is included in the products array.
+    pub payment_initiation: Option<PaymentInitiationConfig<'a>>,
+    /// Specifies options for initializing Link for update mode. Only used together with access_token.
+    pub update: Option<UpdateConfig>,
+    /// A user token generated by `Client::create_user`. Required to initialize Link with the income products.
+    pub user_token: Option<&'a str>,
+    /// The id of an institution to open Link directly into, skipping the institution select pane.
+    pub institution_id: Option<&'a str>,
+    /// Information used to pre-select an institution in Link, e.g. its routing number.
+    pub institution_data: Option<LinkTokenInstitutionData<'a>>,
 }
 
 #[derive(Serialize)]
@@ -78,10 +122,10 @@ struct CreateLinkTokenRequest<'a> {
     secret: &'a str,
     client_name: &'a str,
     language: &'a str,
-    country_codes: &'a [&'a str],
+    country_codes: &'a [CountryCode],
     user: LinkTokenUser<'a>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    products: Option<&'a [&'a str]>,
+    products: Option<&'a [Product]>,
     #[serde(skip_serializing_if = "Option::is_none")]
     webhook: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -89,11 +133,21 @@ struct CreateLinkTokenRequest<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     link_customization_name: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    account_filters: Option<HashMap<&'a str, HashMap<&'a str, Vec<&'a str>>>>,
+    account_filters: Option<AccountFilters>,
     #[serde(skip_serializing_if = "Option::is_none")]
     redirect_uri: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     android_package_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    payment_initiation: Option<PaymentInitiationConfig<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    update: Option<UpdateConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user_token: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    institution_id: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    institution_data: Option<LinkTokenInstitutionData<'a>>,
 }
 
 impl Default for LinkTokenConfigs<'_> {
@@ -102,7 +156,7 @@ impl Default for LinkTokenConfigs<'_> {
             user: Default::default(),
             client_name: "",
             language: "en",
-            country_codes: &["US"],
+            country_codes: &[CountryCode::US],
             products: None,
             webhook: None,
             access_token: None,
@@ -110,6 +164,11 @@ impl Default for LinkTokenConfigs<'_> {
             account_filters: None,
             redirect_uri: None,
             android_package_name: None,
+            payment_initiation: None,
+            update: None,
+            user_token: None,
+            institution_id: None,
+            institution_data: None,
         }
     }
 }
@@ -121,7 +180,7 @@ struct GetLinkTokenRequest<'a> {
     link_token: &'a str,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub struct CreateLinkTokenResponse {
     /// A unique identifier for the request, which can be used for troubleshooting. This identifier, like all Plaid identifiers, is case sensitive.
     pub request_id: String,
@@ -131,7 +190,7 @@ pub struct CreateLinkTokenResponse {
     pub expiration: DateTime<Utc>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub struct GetLinkTokenMetadataResponse {
     /// The products specified in the /link/token/create call.
     pub initial_products: Vec<String>,
@@ -142,14 +201,14 @@ pub struct GetLinkTokenMetadataResponse {
     /// The language specified in the /link/token/create call.
     pub language: Option<String>,
     /// The account_filters specified in the original call to /link/token/create.
-    pub account_filters: HashMap<String, HashMap<String, Vec<String>>>,
+    pub account_filters: AccountFilters,
     /// The redirect_uri specified in the /link/token/create call.
     pub redirect_uri: Option<String>,
     /// The client_name specified in the /link/token/create call.
     pub client_name: Option<String>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub struct GetLinkTokenResponse {
     /// A unique identifier for the request, which can be used for troubleshooting. This identifier, like all Plaid identifiers, is case sensitive.
     pub request_id: String,
@@ -175,11 +234,12 @@ impl Client {
         &self,
         configs: LinkTokenConfigs<'a>,
     ) -> Result<CreateLinkTokenResponse> {
+        validate_country_codes(configs.country_codes)?;
         self.send_request(
             "link/token/create",
             &CreateLinkTokenRequest {
-                client_id: &self.client_id,
-                secret: &self.secret,
+                client_id: self.client_id(),
+                secret: self.secret(),
                 client_name: configs.client_name,
                 language: configs.language,
                 country_codes: configs.country_codes,
@@ -191,6 +251,11 @@ impl Client {
                 account_filters: configs.account_filters,
                 redirect_uri: configs.redirect_uri,
                 android_package_name: configs.android_package_name,
+                payment_initiation: configs.payment_initiation,
+                update: configs.update,
+                user_token: configs.user_token,
+                institution_id: configs.institution_id,
+                institution_data: configs.institution_data,
             },
         )
         .await
@@ -205,8 +270,8 @@ impl Client {
         self.send_request(
             "link/token/get",
             &GetLinkTokenRequest {
-                client_id: &self.client_id,
-                secret: &self.secret,
+                client_id: self.client_id(),
+                secret: self.secret(),
                 link_token,
             },
         )
@@ -216,10 +281,106 @@ impl Client {
 
 #[cfg(test)]
 mod tests {
-    use crate::client::tests::get_test_client;
+    use crate::client::tests::{
+        get_mock_client, get_test_client, SANDBOX_INSTITUTION, TEST_PRODUCTS,
+    };
+    use crate::payment_initiation::PaymentAmount;
+    use crate::types::Money;
 
     use super::*;
 
+    #[test]
+    fn test_account_filters_serialize() {
+        let filters = AccountFilters {
+            depository: Some(AccountSubtypeFilter {
+                account_subtypes: vec![AccountSubtype::Checking, AccountSubtype::Savings],
+            }),
+            credit: Some(AccountSubtypeFilter {
+                account_subtypes: vec![AccountSubtype::CreditCard],
+            }),
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_json::to_value(&filters).unwrap(),
+            serde_json::json!({
+                "depository": {"account_subtypes": ["checking", "savings"]},
+                "credit": {"account_subtypes": ["credit card"]},
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_link_token_user_token() {
+        let (client, transport) = get_mock_client();
+        transport.push_json(
+            "link/token/create",
+            reqwest::StatusCode::OK,
+            serde_json::json!({
+                "link_token": "link-sandbox-af1a0311-da53-4636-b754-dd15cc058176",
+                "expiration": "2020-03-27T12:56:34Z",
+                "request_id": "XQVgFigpGHXkb0b"
+            }),
+        );
+        client
+            .create_link_token(LinkTokenConfigs {
+                user: LinkTokenUser {
+                    client_user_id: "c0e2c4ee-b763-4af5-cfe9-46a46bce883d",
+                    ..Default::default()
+                },
+                client_name: "Plaid Test App",
+                products: Some(&[Product::IncomeVerification]),
+                user_token: Some("user-sandbox-b0e2c4ee-a763-4df5-bfe9-46a46bce993d"),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        let body: serde_json::Value =
+            serde_json::from_slice(&transport.requests()[0].body).unwrap();
+        assert_eq!(
+            body["user_token"],
+            "user-sandbox-b0e2c4ee-a763-4df5-bfe9-46a46bce993d"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_link_token_institution_prefill() {
+        let (client, transport) = get_mock_client();
+        transport.push_json(
+            "link/token/create",
+            reqwest::StatusCode::OK,
+            serde_json::json!({
+                "link_token": "link-sandbox-af1a0311-da53-4636-b754-dd15cc058176",
+                "expiration": "2020-03-27T12:56:34Z",
+                "request_id": "XQVgFigpGHXkb0b"
+            }),
+        );
+        client
+            .create_link_token(LinkTokenConfigs {
+                user: LinkTokenUser {
+                    client_user_id: "c0e2c4ee-b763-4af5-cfe9-46a46bce883d",
+                    ..Default::default()
+                },
+                client_name: "Plaid Test App",
+                products: Some(&[Product::Auth]),
+                institution_id: Some("ins_109508"),
+                institution_data: Some(LinkTokenInstitutionData {
+                    routing_number: Some("011401533"),
+                }),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        let body: serde_json::Value =
+            serde_json::from_slice(&transport.requests()[0].body).unwrap();
+        assert_eq!(body["institution_id"], "ins_109508");
+        assert_eq!(
+            body["institution_data"],
+            serde_json::json!({"routing_number": "011401533"})
+        );
+    }
+
     #[tokio::test]
     async fn test_create_link_token_required() {
         let client = get_test_client();
@@ -232,8 +393,8 @@ mod tests {
                     ..Default::default()
                 },
                 client_name: "Plaid Test",
-                products: Some(&["auth"]),
-                country_codes: &["US"],
+                products: Some(&[Product::Auth]),
+                country_codes: &[CountryCode::US],
                 language: "en",
                 ..Default::default()
             })
@@ -261,21 +422,17 @@ mod tests {
                     date_of_birth: None,
                 },
                 client_name: "Plaid Test",
-                products: Some(&["auth"]),
-                country_codes: &["US"],
+                products: Some(&[Product::Auth]),
+                country_codes: &[CountryCode::US],
                 language: "en",
                 webhook: Some("https://webhook-uri.com"),
                 link_customization_name: Some("default"),
-                account_filters: Some(
-                    vec![(
-                        "depository",
-                        vec![("account_subtypes", vec!["checking", "savings"])]
-                            .into_iter()
-                            .collect(),
-                    )]
-                    .into_iter()
-                    .collect(),
-                ),
+                account_filters: Some(AccountFilters {
+                    depository: Some(AccountSubtypeFilter {
+                        account_subtypes: vec![AccountSubtype::Checking, AccountSubtype::Savings],
+                    }),
+                    ..Default::default()
+                }),
                 ..Default::default()
             })
             .await
@@ -302,21 +459,17 @@ mod tests {
                     date_of_birth: None,
                 },
                 client_name: "Plaid Test",
-                products: Some(&["auth"]),
-                country_codes: &["US"],
+                products: Some(&[Product::Auth]),
+                country_codes: &[CountryCode::US],
                 language: "en",
                 webhook: Some("https://webhook-uri.com"),
                 link_customization_name: Some("default"),
-                account_filters: Some(
-                    vec![(
-                        "depository",
-                        vec![("account_subtypes", vec!["checking", "savings"])]
-                            .into_iter()
-                            .collect(),
-                    )]
-                    .into_iter()
-                    .collect(),
-                ),
+                account_filters: Some(AccountFilters {
+                    depository: Some(AccountSubtypeFilter {
+                        account_subtypes: vec![AccountSubtype::Checking, AccountSubtype::Savings],
+                    }),
+                    ..Default::default()
+                }),
                 ..Default::default()
             })
             .await
@@ -336,7 +489,16 @@ mod tests {
         );
         assert_eq!(get_resp.metadata.country_codes, &["US"]);
         assert_eq!(get_resp.metadata.language, Some("en".to_string()));
-        assert_eq!(get_resp.metadata.account_filters.len(), 1);
+        assert_eq!(
+            get_resp
+                .metadata
+                .account_filters
+                .depository
+                .unwrap()
+                .account_subtypes,
+            &[AccountSubtype::Checking, AccountSubtype::Savings]
+        );
+        assert!(get_resp.metadata.account_filters.credit.is_none());
         assert_eq!(
             get_resp.metadata.client_name,
             Some("Plaid Test".to_string())
@@ -344,4 +506,81 @@ mod tests {
         assert_ne!(get_resp.expiration.unwrap().timestamp(), 0);
         assert_ne!(get_resp.created_at.unwrap().timestamp(), 0);
     }
+
+    #[tokio::test]
+    async fn test_create_link_token_update_mode() {
+        let client = get_test_client();
+        let sandbox_resp = client
+            .create_sandbox_public_token(SANDBOX_INSTITUTION, TEST_PRODUCTS, None)
+            .await
+            .unwrap();
+        let token_resp = client
+            .exchange_public_token(&sandbox_resp.public_token)
+            .await
+            .unwrap();
+        client
+            .reset_sandbox_item(&token_resp.access_token)
+            .await
+            .unwrap();
+
+        let time_now = Utc::now().to_rfc3339();
+        let resp = client
+            .create_link_token(LinkTokenConfigs {
+                user: LinkTokenUser {
+                    client_user_id: &time_now,
+                    ..Default::default()
+                },
+                client_name: "Plaid Test",
+                access_token: Some(&token_resp.access_token),
+                update: Some(UpdateConfig {
+                    account_selection_enabled: true,
+                }),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert!(resp.link_token.starts_with("link-sandbox"));
+    }
+
+    #[tokio::test]
+    async fn test_create_link_token_payment_initiation() {
+        let client = get_test_client();
+
+        let recipient_resp = client
+            .create_payment_recipient("Wonder Wallet", "GB29NWBK60161331926819", None)
+            .await
+            .unwrap();
+        let payment_resp = client
+            .create_payment(
+                &recipient_resp.recipient_id,
+                "TestPayment",
+                &PaymentAmount {
+                    currency: "GBP".to_string(),
+                    value: Money::from(100),
+                },
+                None,
+            )
+            .await
+            .unwrap();
+
+        let time_now = Utc::now().to_rfc3339();
+        let resp = client
+            .create_link_token(LinkTokenConfigs {
+                user: LinkTokenUser {
+                    client_user_id: &time_now,
+                    ..Default::default()
+                },
+                client_name: "Plaid Test",
+                products: Some(&[Product::PaymentInitiation]),
+                country_codes: &[CountryCode::GB],
+                language: "en",
+                payment_initiation: Some(PaymentInitiationConfig {
+                    payment_id: &payment_resp.payment_id,
+                }),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert!(resp.link_token.starts_with("link-sandbox"));
+    }
 }
@@ -0,0 +1,267 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::client::Client;
+use crate::errors::Result;
+use crate::types::Amount;
+
+string_enum! {
+    /// The status of a payment.
+    pub enum PaymentStatus {
+        /// The initial phase of the payment, waiting for the user to complete the payment in Link.
+        InputNeeded => "PAYMENT_STATUS_INPUT_NEEDED",
+        /// The payment is currently being processed.
+        Processing => "PAYMENT_STATUS_PROCESSING",
+        /// The payment has been successfully initiated and is considered complete.
+        Initiated => "PAYMENT_STATUS_INITIATED",
+        /// The payment has completed.
+        Completed => "PAYMENT_STATUS_COMPLETED",
+        /// The payment has failed due to insufficient funds.
+        InsufficientFunds => "PAYMENT_STATUS_INSUFFICIENT_FUNDS",
+        /// The payment has failed to be initiated.
+        Failed => "PAYMENT_STATUS_FAILED",
+        /// The payment has been blocked.
+        Blocked => "PAYMENT_STATUS_BLOCKED",
+        /// The payment status is unknown.
+        Unknown => "PAYMENT_STATUS_UNKNOWN",
+        /// The payment has been successfully executed and funds have left the payer's account.
+        Executed => "PAYMENT_STATUS_EXECUTED",
+        /// The payment is awaiting authorisation by the bank.
+        Authorising => "PAYMENT_STATUS_AUTHORISING",
+        /// The payment has been cancelled by the user.
+        Cancelled => "PAYMENT_STATUS_CANCELLED",
+        /// The standing order has been established.
+        Established => "PAYMENT_STATUS_ESTABLISHED",
+        /// The payment has been rejected by the bank.
+        Rejected => "PAYMENT_STATUS_REJECTED",
+        _ => Other,
+    }
+}
+
+/// The amount and currency of a payment. The value must contain at most two digits of precision e.g. 1.23, and the minimum accepted value is 1.
+pub type PaymentAmount = Amount;
+
+/// The optional address of the payment recipient.
+#[derive(Serialize, Debug, Clone)]
+pub struct PaymentRecipientAddress<'a> {
+    /// An array of length 1-2 representing the street address where the recipient is located.
+    pub street: &'a [&'a str],
+    /// The city where the recipient is located.
+    pub city: &'a str,
+    /// The postal code where the recipient is located.
+    pub postal_code: &'a str,
+    /// The ISO 3166-1 alpha-2 country code where the recipient is located.
+    pub country: &'a str,
+}
+
+#[derive(Serialize)]
+struct CreatePaymentRecipientRequest<'a> {
+    client_id: &'a str,
+    secret: &'a str,
+    name: &'a str,
+    iban: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    address: Option<PaymentRecipientAddress<'a>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CreatePaymentRecipientResponse {
+    /// A unique identifier for the request, which can be used for troubleshooting. This identifier, like all Plaid identifiers, is case sensitive.
+    pub request_id: String,
+    /// A unique ID identifying the recipient
+    pub recipient_id: String,
+}
+
+#[derive(Serialize)]
+struct CreatePaymentRequest<'a> {
+    client_id: &'a str,
+    secret: &'a str,
+    recipient_id: &'a str,
+    reference: &'a str,
+    amount: &'a PaymentAmount,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CreatePaymentResponse {
+    /// A unique identifier for the request, which can be used for troubleshooting. This identifier, like all Plaid identifiers, is case sensitive.
+    pub request_id: String,
+    /// A unique ID identifying the payment
+    pub payment_id: String,
+    /// For a payment returned by this endpoint, status will be PAYMENT_STATUS_INPUT_NEEDED.
+    pub status: PaymentStatus,
+}
+
+#[derive(Serialize)]
+struct GetPaymentRequest<'a> {
+    client_id: &'a str,
+    secret: &'a str,
+    payment_id: &'a str,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GetPaymentResponse {
+    /// A unique identifier for the request, which can be used for troubleshooting. This identifier, like all Plaid identifiers, is case sensitive.
+    pub request_id: String,
+    /// The ID of the payment. Like all Plaid identifiers, the payment_id is case sensitive.
+    pub payment_id: String,
+    /// The amount and currency of a payment
+    pub amount: PaymentAmount,
+    /// The status of the payment.
+    pub status: PaymentStatus,
+    /// The ID of the recipient
+    pub recipient_id: String,
+    /// A reference for the payment.
+    pub reference: String,
+    /// The value of the reference sent to the bank after adjustment to pass bank validation rules.
+    pub adjusted_reference: Option<String>,
+    /// The date and time of the last time the status was updated, in IS0 8601 format
+    pub last_status_update: DateTime<Utc>,
+}
+
+impl Client {
+    /// Create payment recipient.
+    ///
+    /// Create a payment recipient for payment initiation. The recipient must be in Europe, within a country that is a member of the Single Euro Payment Area (SEPA). The recipient_id returned can be used to create payments with /payment_initiation/payment/create.
+    ///
+    /// * `name` - The name of the recipient
+    /// * `iban` - The International Bank Account Number (IBAN) for the recipient.
+    /// * `address` - The optional address of the payment recipient.
+    pub async fn create_payment_recipient<'a>(
+        &self,
+        name: &str,
+        iban: &str,
+        address: Option<PaymentRecipientAddress<'a>>,
+    ) -> Result<CreatePaymentRecipientResponse> {
+        self.send_request(
+            "payment_initiation/recipient/create",
+            &CreatePaymentRecipientRequest {
+                client_id: self.client_id(),
+                secret: self.secret(),
+                name,
+                iban,
+                address,
+            },
+        )
+        .await
+    }
+
+    /// Create a payment.
+    ///
+    /// After creating a payment recipient, you can use the /payment_initiation/payment/create endpoint to create a payment to that recipient. The payment_id returned can be passed to /link/token/create to launch the Payment Initiation flow in Link.
+    ///
+    /// * `recipient_id` - The ID of the recipient the payment is for.
+    /// * `reference` - A reference for the payment. This must be an alphanumeric string with at most 18 characters and must not contain any special characters.
+    /// * `amount` - The amount and currency of the payment.
+    /// * `idempotency_key` - An optional key sent in the Plaid-Idempotency-Key header, so that retrying the request does not create a second payment.
+    pub async fn create_payment(
+        &self,
+        recipient_id: &str,
+        reference: &str,
+        amount: &PaymentAmount,
+        idempotency_key: Option<&str>,
+    ) -> Result<CreatePaymentResponse> {
+        amount.validate()?;
+        self.send_request_with_key(
+            "payment_initiation/payment/create",
+            &CreatePaymentRequest {
+                client_id: self.client_id(),
+                secret: self.secret(),
+                recipient_id,
+                reference,
+                amount,
+            },
+            idempotency_key,
+        )
+        .await
+        .map(|(resp, _)| resp)
+    }
+
+    /// Get payment details.
+    ///
+    /// The /payment_initiation/payment/get endpoint can be used to check the status of a payment, as well as to receive basic information such as recipient and payment amount.
+    ///
+    /// * `payment_id` - The payment_id returned from /payment_initiation/payment/create.
+    pub async fn get_payment(&self, payment_id: &str) -> Result<GetPaymentResponse> {
+        self.send_request(
+            "payment_initiation/payment/get",
+            &GetPaymentRequest {
+                client_id: self.client_id(),
+                secret: self.secret(),
+                payment_id,
+            },
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::tests::{get_mock_client, get_test_client};
+    use crate::types::Money;
+
+    #[tokio::test]
+    async fn test_create_payment_idempotency_key() {
+        let (client, transport) = get_mock_client();
+        transport.push_json(
+            "payment_initiation/payment/create",
+            reqwest::StatusCode::OK,
+            serde_json::json!({
+                "request_id": "4ciYVmesrySiUAB",
+                "payment_id": "payment-id-sandbox-feca8a7a-5591-4aef-9297-f3062bb735d3",
+                "status": "PAYMENT_STATUS_INPUT_NEEDED"
+            }),
+        );
+        let amount = PaymentAmount {
+            currency: "GBP".to_string(),
+            value: Money::from(100),
+        };
+        client
+            .create_payment(
+                "recipient-id-sandbox-9b6b4679-914b-445b-9450-efbdb80296f6",
+                "TestPayment",
+                &amount,
+                Some("f1c0b2a4-6e3d-4c5b-8a9f-7d2e1b0c3a45"),
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            transport.requests()[0].headers["Plaid-Idempotency-Key"],
+            "f1c0b2a4-6e3d-4c5b-8a9f-7d2e1b0c3a45"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_payment_then_get() {
+        let client = get_test_client();
+        let recipient_resp = client
+            .create_payment_recipient(
+                "Wonder Wallet",
+                "GB29NWBK60161331926819",
+                Some(PaymentRecipientAddress {
+                    street: &["96 Guild Street", "9th Floor"],
+                    city: "London",
+                    postal_code: "SE14 8JW",
+                    country: "GB",
+                }),
+            )
+            .await
+            .unwrap();
+        assert!(!recipient_resp.recipient_id.is_empty());
+
+        let amount = PaymentAmount {
+            currency: "GBP".to_string(),
+            value: Money::from(100),
+        };
+        let payment_resp = client
+            .create_payment(&recipient_resp.recipient_id, "TestPayment", &amount, None)
+            .await
+            .unwrap();
+        assert_eq!(payment_resp.status, PaymentStatus::InputNeeded);
+
+        let resp = client.get_payment(&payment_resp.payment_id).await.unwrap();
+        assert_eq!(resp.payment_id, payment_resp.payment_id);
+        assert_eq!(resp.recipient_id, recipient_resp.recipient_id);
+        assert_eq!(resp.amount.currency, "GBP");
+    }
+}
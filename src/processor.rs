@@ -1,18 +1,63 @@
 use serde::{Deserialize, Serialize};
 
+use crate::accounts::Account;
+use crate::auth::{ACHNumber, BACSNumber, EFTNumber, IBANNumber};
 use crate::client::Client;
 use crate::errors::Result;
 
+string_enum! {
+    /// A Plaid partner that processor tokens can be created for.
+    pub enum Processor {
+        /// ACHQ.
+        Achq => "achq",
+        /// Check.
+        Check => "check",
+        /// Checkbook.
+        Checkbook => "checkbook",
+        /// Circle.
+        Circle => "circle",
+        /// DriveWealth.
+        Drivewealth => "drivewealth",
+        /// Dwolla.
+        Dwolla => "dwolla",
+        /// Galileo.
+        Galileo => "galileo",
+        /// Interactive Brokers.
+        InteractiveBrokers => "interactive_brokers",
+        /// Modern Treasury.
+        ModernTreasury => "modern_treasury",
+        /// Ocrolus.
+        Ocrolus => "ocrolus",
+        /// Prime Trust.
+        PrimeTrust => "prime_trust",
+        /// Rize.
+        Rize => "rize",
+        /// Sila Money.
+        SilaMoney => "sila_money",
+        /// Unit.
+        Unit => "unit",
+        /// Velox.
+        Velox => "velox",
+        /// Vesta.
+        Vesta => "vesta",
+        /// VoPay.
+        Vopay => "vopay",
+        /// Wyre.
+        Wyre => "wyre",
+        _ => Other,
+    }
+}
+
 #[derive(Serialize)]
 struct CreateProcessorTokenRequest<'a> {
     client_id: &'a str,
     secret: &'a str,
     access_token: &'a str,
     account_id: &'a str,
-    processor: &'a str,
+    processor: Processor,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct CreateProcessorTokenResponse {
     /// A unique identifier for the request, which can be used for troubleshooting. This identifier, like all Plaid identifiers, is case sensitive.
     pub request_id: String,
@@ -20,6 +65,43 @@ pub struct CreateProcessorTokenResponse {
     pub processor_token: String,
 }
 
+#[derive(Serialize)]
+struct ProcessorRequest<'a> {
+    client_id: &'a str,
+    secret: &'a str,
+    processor_token: &'a str,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GetProcessorBalanceResponse {
+    /// A unique identifier for the request, which can be used for troubleshooting. This identifier, like all Plaid identifiers, is case sensitive.
+    pub request_id: String,
+    /// The account associated with the processor token, with real-time balance information.
+    pub account: Account,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ProcessorNumbers {
+    /// Identifying information for transferring money to or from a US account via ACH or wire transfer.
+    pub ach: Option<ACHNumber>,
+    /// Identifying information for transferring money to or from a Canadian bank account via EFT.
+    pub eft: Option<EFTNumber>,
+    /// Identifying information for transferring money to or from an international bank account via wire transfer.
+    pub international: Option<IBANNumber>,
+    /// Identifying information for transferring money to or from a UK bank account via BACS.
+    pub bacs: Option<BACSNumber>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GetProcessorAuthResponse {
+    /// A unique identifier for the request, which can be used for troubleshooting. This identifier, like all Plaid identifiers, is case sensitive.
+    pub request_id: String,
+    /// The account associated with the processor token.
+    pub account: Account,
+    /// An object containing identifying numbers used for making electronic transfers to and from the account.
+    pub numbers: ProcessorNumbers,
+}
+
 impl Client {
     /// Create processor token.
     ///
@@ -27,18 +109,18 @@ impl Client {
     ///
     /// * `access_token` - The access token associated with the Item data is being requested for.
     /// * `account_id` - The account_id value obtained from the onSuccess callback in Link.
-    /// * `processor` - The processor you are integrating with. Valid values are "achq", "check", "checkbook", "circle", "drivewealth", "dwolla", "galileo", "interactive_brokers", "modern_treasury", "ocrolus", "prime_trust", "rize", "sila_money", "unit", "velox", "vesta", "vopay", "wyre"
+    /// * `processor` - The processor you are integrating with.
     pub async fn create_processor_token(
         &self,
         access_token: &str,
         account_id: &str,
-        processor: &str,
+        processor: Processor,
     ) -> Result<CreateProcessorTokenResponse> {
         self.send_request(
             "processor/token/create",
             &CreateProcessorTokenRequest {
-                client_id: &self.client_id,
-                secret: &self.secret,
+                client_id: self.client_id(),
+                secret: self.secret(),
                 access_token,
                 account_id,
                 processor,
@@ -46,4 +128,160 @@ impl Client {
         )
         .await
     }
+
+    /// Retrieve balance data for a processor token.
+    ///
+    /// The /processor/balance/get endpoint returns the real-time balance for the account associated with a given processor_token.
+    ///
+    /// * `processor_token` - The processor token obtained from the Plaid integration partner.
+    pub async fn get_processor_balance(
+        &self,
+        processor_token: &str,
+    ) -> Result<GetProcessorBalanceResponse> {
+        self.send_request(
+            "processor/balance/get",
+            &ProcessorRequest {
+                client_id: self.client_id(),
+                secret: self.secret(),
+                processor_token,
+            },
+        )
+        .await
+    }
+
+    /// Retrieve auth data for a processor token.
+    ///
+    /// The /processor/auth/get endpoint returns the bank account and bank identification numbers (such as routing numbers, for US accounts), for the account associated with a given processor_token.
+    ///
+    /// * `processor_token` - The processor token obtained from the Plaid integration partner.
+    pub async fn get_processor_auth(
+        &self,
+        processor_token: &str,
+    ) -> Result<GetProcessorAuthResponse> {
+        self.send_request(
+            "processor/auth/get",
+            &ProcessorRequest {
+                client_id: self.client_id(),
+                secret: self.secret(),
+                processor_token,
+            },
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::tests::get_mock_client;
+    use crate::fixtures::{account_fixture, merge};
+    use crate::types::Money;
+    use reqwest::StatusCode;
+
+    #[tokio::test]
+    async fn test_create_processor_token() {
+        let (client, transport) = get_mock_client();
+        transport.push_json(
+            "processor/token/create",
+            StatusCode::OK,
+            serde_json::json!({
+                "processor_token": "processor-sandbox-0asd1-a92nc",
+                "request_id": "xrQNYZ7Zoh6R7gV"
+            }),
+        );
+        let resp = client
+            .create_processor_token(
+                "access-sandbox-123",
+                "QeWDdwRXyMSeBXz6jzVeSX5Jjgm",
+                Processor::ModernTreasury,
+            )
+            .await
+            .unwrap();
+        assert_eq!(resp.processor_token, "processor-sandbox-0asd1-a92nc");
+
+        let body: serde_json::Value =
+            serde_json::from_slice(&transport.requests()[0].body).unwrap();
+        assert_eq!(body["processor"], "modern_treasury");
+    }
+
+    #[tokio::test]
+    async fn test_get_processor_auth() {
+        let (client, transport) = get_mock_client();
+        transport.push_json(
+            "processor/auth/get",
+            StatusCode::OK,
+            serde_json::json!({
+                "request_id": "1zlMf2v3LaQJxIP",
+                "account": {
+                    "account_id": "QeWDdwRXyMSeBXz6jzVeSX5Jjgm",
+                    "balances": {"available": 100, "current": 110, "limit": null, "iso_currency_code": "USD", "unofficial_currency_code": null},
+                    "mask": "0000",
+                    "name": "Plaid Checking",
+                    "official_name": "Plaid Gold Standard 0% Interest Checking",
+                    "type": "depository",
+                    "subtype": "checking"
+                },
+                "numbers": {
+                    "ach": {
+                        "account_id": "QeWDdwRXyMSeBXz6jzVeSX5Jjgm",
+                        "account": "1111222233330000",
+                        "routing": "011401533",
+                        "wire_routing": "021000021"
+                    },
+                    "eft": null,
+                    "international": null,
+                    "bacs": null
+                }
+            }),
+        );
+
+        let resp = client
+            .get_processor_auth("processor-sandbox-0asd1-a92nc")
+            .await
+            .unwrap();
+        assert_eq!(resp.account.account_id, "QeWDdwRXyMSeBXz6jzVeSX5Jjgm");
+        assert_eq!(resp.numbers.ach.unwrap().routing, "011401533");
+
+        let body: serde_json::Value =
+            serde_json::from_slice(&transport.requests()[0].body).unwrap();
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "client_id": "client_id",
+                "secret": "secret",
+                "processor_token": "processor-sandbox-0asd1-a92nc"
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_processor_balance() {
+        let (client, transport) = get_mock_client();
+        transport.push_json(
+            "processor/balance/get",
+            StatusCode::OK,
+            serde_json::json!({
+                "request_id": "1zlMf2v3LaQJxIP",
+                "account": merge(
+                    account_fixture("QeWDdwRXyMSeBXz6jzVeSX5Jjgm", "depository", Some("checking")),
+                    serde_json::json!({"balances": {"available": 100.5, "current": 110.25}}),
+                )
+            }),
+        );
+
+        let resp = client
+            .get_processor_balance("processor-sandbox-0asd1-a92nc")
+            .await
+            .unwrap();
+        let balances = &resp.account.balances;
+        assert_eq!(balances.available, Some("100.5".parse().unwrap()));
+        assert_eq!(balances.current, "110.25".parse::<Money>().unwrap());
+        assert!(balances.limit.is_none());
+        assert_eq!(balances.iso_currency_code.as_deref(), Some("USD"));
+
+        let requests = transport.requests();
+        assert_eq!(requests[0].url.path(), "/processor/balance/get");
+        let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+        assert_eq!(body["processor_token"], "processor-sandbox-0asd1-a92nc");
+    }
 }
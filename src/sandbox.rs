@@ -1,17 +1,54 @@
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 
+use crate::accounts::{Account, GetAccountsOptions};
 use crate::client::Client;
-use crate::errors::Result;
+use crate::errors::{Error, Result};
+use crate::types::Product;
+use crate::webhooks::{WebhookCode, WebhookEvent};
+
+/// How many times `complete_sandbox_microdeposit_verification` checks the account before giving up.
+const MICRODEPOSIT_VERIFICATION_ATTEMPTS: u32 = 5;
+/// The delay between checks of the account in `complete_sandbox_microdeposit_verification`.
+const MICRODEPOSIT_VERIFICATION_DELAY: std::time::Duration = std::time::Duration::from_millis(500);
 
 #[derive(Serialize)]
 struct CreateSandboxPublicTokenRequest<'a> {
     client_id: &'a str,
     secret: &'a str,
     institution_id: &'a str,
-    initial_products: &'a [&'a str],
+    initial_products: &'a [Product],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    options: Option<CreateSandboxPublicTokenOptions<'a>>,
+}
+
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct CreateSandboxPublicTokenTransactionsOptions {
+    /// The earliest date for which to fetch transaction history. Dates should be formatted as YYYY-MM-DD.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<NaiveDate>,
+    /// The most recent date for which to fetch transaction history. Dates should be formatted as YYYY-MM-DD.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<NaiveDate>,
+}
+
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct CreateSandboxPublicTokenOptions<'a> {
+    /// Specify a webhook to associate with the new Item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webhook: Option<&'a str>,
+    /// Test username to use for the creation of the Sandbox Item. Default value is user_good.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub override_username: Option<&'a str>,
+    /// Test password to use for the creation of the Sandbox Item. Default value is pass_good.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub override_password: Option<&'a str>,
+    /// An optional set of parameters corresponding to transactions options.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transactions: Option<CreateSandboxPublicTokenTransactionsOptions>,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct CreateSandboxPublicTokenResponse {
     /// A unique identifier for the request, which can be used for troubleshooting. This identifier, like all Plaid identifiers, is case sensitive.
     pub request_id: String,
@@ -26,7 +63,7 @@ struct ResetSandboxItemRequest<'a> {
     access_token: &'a str,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ResetSandboxItemResponse {
     /// A unique identifier for the request, which can be used for troubleshooting. This identifier, like all Plaid identifiers, is case sensitive.
     pub request_id: String,
@@ -34,16 +71,27 @@ pub struct ResetSandboxItemResponse {
     pub reset_login: bool,
 }
 
+string_enum! {
+    /// A verification status that can be set on a Sandbox account.
+    pub enum SandboxVerificationStatus {
+        /// Simulates the account being verified by Automated Micro-deposits.
+        AutomaticallyVerified => "automatically_verified",
+        /// Simulates Automated Micro-deposit verification expiring.
+        VerificationExpired => "verification_expired",
+        _ => Other,
+    }
+}
+
 #[derive(Serialize)]
 struct SetSandboxItemVerificationStatusRequest<'a> {
     client_id: &'a str,
     secret: &'a str,
     access_token: &'a str,
     account_id: &'a str,
-    verification_status: &'a str,
+    verification_status: SandboxVerificationStatus,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct SetSandboxItemVerificationStatusResponse {
     /// A unique identifier for the request, which can be used for troubleshooting. This identifier, like all Plaid identifiers, is case sensitive.
     pub request_id: String,
@@ -54,10 +102,12 @@ struct FireWebhookRequest<'a> {
     client_id: &'a str,
     secret: &'a str,
     access_token: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    webhook_type: Option<&'a str>,
     webhook_code: &'a str,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct FireWebhookResponse {
     /// A unique identifier for the request, which can be used for troubleshooting. This identifier, like all Plaid identifiers, is case sensitive.
     pub request_id: String,
@@ -65,6 +115,159 @@ pub struct FireWebhookResponse {
     pub webhook_fired: bool,
 }
 
+string_enum! {
+    /// The income verification status to simulate with `fire_sandbox_income_webhook`.
+    pub enum SandboxIncomeVerificationStatus {
+        /// The income verification processing has completed.
+        ProcessingComplete => "VERIFICATION_STATUS_PROCESSING_COMPLETE",
+        /// The income verification processing has failed.
+        ProcessingFailed => "VERIFICATION_STATUS_PROCESSING_FAILED",
+        /// The income verification is waiting on the user's approval.
+        PendingApproval => "VERIFICATION_STATUS_PENDING_APPROVAL",
+        _ => Other,
+    }
+}
+
+#[derive(Serialize)]
+struct FireSandboxIncomeWebhookRequest<'a> {
+    client_id: &'a str,
+    secret: &'a str,
+    item_id: &'a str,
+    webhook: &'a str,
+    webhook_code: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    verification_status: Option<SandboxIncomeVerificationStatus>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FireSandboxIncomeWebhookResponse {
+    /// A unique identifier for the request, which can be used for troubleshooting. This identifier, like all Plaid identifiers, is case sensitive.
+    pub request_id: String,
+}
+
+string_enum! {
+    /// A transfer event that can be simulated in the Sandbox.
+    pub enum SandboxTransferEventType {
+        /// The transfer has been submitted to the network.
+        Posted => "posted",
+        /// The transfer has settled.
+        Settled => "settled",
+        /// The transfer failed before reaching the network.
+        Failed => "failed",
+        /// The transfer was returned by the receiving bank.
+        Returned => "returned",
+        _ => Other,
+    }
+}
+
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct SandboxTransferFailureReason<'a> {
+    /// The ACH return code, e.g. R01. A return code will be provided if and only if the transfer status is returned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ach_return_code: Option<&'a str>,
+    /// A human-readable description of the reason for the failure or reversal.
+    pub description: &'a str,
+}
+
+#[derive(Serialize)]
+struct SimulateSandboxTransferRequest<'a> {
+    client_id: &'a str,
+    secret: &'a str,
+    transfer_id: &'a str,
+    event_type: SandboxTransferEventType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    failure_reason: Option<SandboxTransferFailureReason<'a>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SimulateSandboxTransferResponse {
+    /// A unique identifier for the request, which can be used for troubleshooting. This identifier, like all Plaid identifiers, is case sensitive.
+    pub request_id: String,
+}
+
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct CreateSandboxProcessorTokenOptions<'a> {
+    /// Test username to use for the creation of the Sandbox Item. Default value is user_good.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub override_username: Option<&'a str>,
+    /// Test password to use for the creation of the Sandbox Item. Default value is pass_good.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub override_password: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+struct CreateSandboxProcessorTokenRequest<'a> {
+    client_id: &'a str,
+    secret: &'a str,
+    institution_id: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    options: Option<CreateSandboxProcessorTokenOptions<'a>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CreateSandboxProcessorTokenResponse {
+    /// A unique identifier for the request, which can be used for troubleshooting. This identifier, like all Plaid identifiers, is case sensitive.
+    pub request_id: String,
+    /// A processor token that can be used to call the /processor/ endpoints.
+    pub processor_token: String,
+}
+
+/// Builds the webhook that `Client::fire_webhook` is expected to deliver, for testing webhook handlers in-process.
+///
+/// The event is built locally, not received from Plaid, so fields whose values only Plaid knows, such as new_transactions or the account_id of an AUTH webhook, are None. Handlers should still be tested against webhooks actually delivered by the Sandbox.
+///
+/// Supports the combinations of webhook_type and webhook_code that `fire_webhook` supports. When webhook_type is None, it is inferred from webhook_code.
+///
+/// * `item_id` - The item_id of the Item the webhook is fired for.
+/// * `webhook_type` - The webhook type. Possible values: TRANSACTIONS, ITEM, AUTH.
+/// * `webhook_code` - The webhook code.
+pub fn expected_sandbox_webhook_event(
+    item_id: &str,
+    webhook_type: Option<&str>,
+    webhook_code: &str,
+) -> Result<WebhookEvent> {
+    let webhook_type = match (webhook_type, webhook_code) {
+        (Some(webhook_type), _) => webhook_type,
+        (None, "DEFAULT_UPDATE") => "TRANSACTIONS",
+        (None, "NEW_ACCOUNTS_AVAILABLE") => "ITEM",
+        (None, "SMS_MICRODEPOSITS_VERIFICATION") => "AUTH",
+        (None, _) => {
+            return Err(Error::InvalidRequest(format!(
+                "unknown Sandbox webhook code {}",
+                webhook_code
+            )))
+        }
+    };
+    let code = WebhookCode::from(webhook_code);
+    let item_id = item_id.to_string();
+    match (webhook_type, webhook_code) {
+        ("TRANSACTIONS", "DEFAULT_UPDATE") => Ok(WebhookEvent::Transactions {
+            code,
+            item_id,
+            new_transactions: None,
+            removed_transactions: None,
+            error: None,
+        }),
+        ("ITEM", "NEW_ACCOUNTS_AVAILABLE") => Ok(WebhookEvent::Item {
+            code,
+            item_id,
+            error: None,
+            new_webhook_url: None,
+            consent_expiration_time: None,
+        }),
+        ("AUTH", "SMS_MICRODEPOSITS_VERIFICATION") => Ok(WebhookEvent::Auth {
+            code,
+            item_id,
+            account_id: None,
+            error: None,
+        }),
+        _ => Err(Error::InvalidRequest(format!(
+            "webhook code {} cannot be fired with webhook type {} in Sandbox",
+            webhook_code, webhook_type
+        ))),
+    }
+}
+
 impl Client {
     /// Create a test Item.
     ///
@@ -72,18 +275,21 @@ impl Client {
     ///
     /// * `institution_id` - The ID of the institution the Item will be associated with.
     /// * `initial_products` - The products to initially pull for the Item. May be any products that the specified institution_id  supports. This array may not be empty.
-    pub async fn create_sandbox_public_token(
+    /// * `options` - An optional set of options to be used when configuring the Item.
+    pub async fn create_sandbox_public_token<'a>(
         &self,
         institution_id: &str,
-        initial_products: &[&str],
+        initial_products: &[Product],
+        options: Option<CreateSandboxPublicTokenOptions<'a>>,
     ) -> Result<CreateSandboxPublicTokenResponse> {
         self.send_request(
             "sandbox/public_token/create",
             &CreateSandboxPublicTokenRequest {
-                client_id: &self.client_id,
-                secret: &self.secret,
+                client_id: self.client_id(),
+                secret: self.secret(),
                 institution_id,
                 initial_products,
+                options,
             },
         )
         .await
@@ -100,8 +306,8 @@ impl Client {
         self.send_request(
             "sandbox/item/reset_login",
             &ResetSandboxItemRequest {
-                client_id: &self.client_id,
-                secret: &self.secret,
+                client_id: self.client_id(),
+                secret: self.secret(),
                 access_token,
             },
         )
@@ -112,22 +318,24 @@ impl Client {
     ///
     /// The /sandbox/item/set_verification_status endpoint can be used to change the verification status of an Item in in the Sandbox in order to simulate the Automated Micro-deposit flow.
     ///
+    /// Only the Automated Micro-deposit flow can be driven from the API. Manual (Same Day) Micro-deposits are verified by the user entering the two deposited amounts in Link's update mode; in the Sandbox, the amounts are always $0.01 and $0.02.
+    ///
     /// Note that not all Plaid developer accounts are enabled for micro-deposit based verification by default. Your account must be enabled for this feature in order to test it in Sandbox. To enable this features or check your status, contact your account manager or submit a product access Support ticket.
     ///
     /// * `access_token` - The access token associated with the Item data is being requested for.
     /// * `account_id` - The account_id of the account whose verification status is to be modified
-    /// * `verification_status` - The verification status to set the account to. Possible values: automatically_verified, verification_expired.
+    /// * `verification_status` - The verification status to set the account to.
     pub async fn set_sandbox_verification_status(
         &self,
         access_token: &str,
         account_id: &str,
-        verification_status: &str,
+        verification_status: SandboxVerificationStatus,
     ) -> Result<SetSandboxItemVerificationStatusResponse> {
         self.send_request(
             "sandbox/item/set_verification_status",
             &SetSandboxItemVerificationStatusRequest {
-                client_id: &self.client_id,
-                secret: &self.secret,
+                client_id: self.client_id(),
+                secret: self.secret(),
                 access_token,
                 account_id,
                 verification_status,
@@ -136,39 +344,411 @@ impl Client {
         .await
     }
 
+    /// Complete Automated Micro-deposit verification of a Sandbox account.
+    ///
+    /// Sets the account's verification status to automatically_verified and then polls /accounts/get until the account reports a verified status, returning the verified account. The Item should be created with the auth product at an institution that supports Automated Micro-deposits, e.g. with the test credentials user_good / microdeposits_good.
+    ///
+    /// Returns `Error::InvalidRequest` if the account is not part of the Item or is still not verified after several checks.
+    ///
+    /// * `access_token` - The access token associated with the Item data is being requested for.
+    /// * `account_id` - The account_id of the account whose verification status is to be modified.
+    pub async fn complete_sandbox_microdeposit_verification(
+        &self,
+        access_token: &str,
+        account_id: &str,
+    ) -> Result<Account> {
+        self.set_sandbox_verification_status(
+            access_token,
+            account_id,
+            SandboxVerificationStatus::AutomaticallyVerified,
+        )
+        .await?;
+        for attempt in 0..MICRODEPOSIT_VERIFICATION_ATTEMPTS {
+            if attempt > 0 {
+                tokio::time::sleep(MICRODEPOSIT_VERIFICATION_DELAY).await;
+            }
+            let resp = self
+                .get_accounts(
+                    access_token,
+                    Some(GetAccountsOptions {
+                        account_ids: Some(&[account_id]),
+                    }),
+                )
+                .await?;
+            let account = resp
+                .accounts
+                .into_iter()
+                .find(|account| account.account_id == account_id)
+                .ok_or_else(|| {
+                    Error::InvalidRequest(format!("account {} not found on the Item", account_id))
+                })?;
+            if matches!(&account.verification_status, Some(status) if status.is_verified()) {
+                return Ok(account);
+            }
+        }
+        Err(Error::InvalidRequest(format!(
+            "account {} was not verified after {} checks",
+            account_id, MICRODEPOSIT_VERIFICATION_ATTEMPTS
+        )))
+    }
+
     /// Fire a test webhook.
     ///
-    /// The /sandbox/item/fire_webhook endpoint is used to test that code correctly handles webhooks. Calling this endpoint triggers a Transactions DEFAULT_UPDATE webhook to be fired for a given Sandbox Item. If the Item does not support Transactions, a SANDBOX_PRODUCT_NOT_ENABLED error will result.
+    /// The /sandbox/item/fire_webhook endpoint is used to test that code correctly handles webhooks. Calling this endpoint triggers the given webhook to be fired for a given Sandbox Item. If the Item does not support the product the webhook belongs to, a SANDBOX_PRODUCT_NOT_ENABLED error will result.
+    ///
+    /// The supported combinations of webhook_type and webhook_code are:
+    /// - TRANSACTIONS: DEFAULT_UPDATE
+    /// - ITEM: NEW_ACCOUNTS_AVAILABLE
+    /// - AUTH: SMS_MICRODEPOSITS_VERIFICATION
+    ///
+    /// When webhook_type is omitted, Plaid infers it from webhook_code.
     ///
     /// * `access_token` - The access token associated with the Item data is being requested for.
-    /// * `webhook_code` - The following values for webhook_code are supported: DEFAULT_UPDATE.
+    /// * `webhook_type` - The webhook types that can be fired by this test endpoint. Possible values: TRANSACTIONS, ITEM, AUTH.
+    /// * `webhook_code` - The webhook code to send.
     pub async fn fire_webhook(
         &self,
         access_token: &str,
+        webhook_type: Option<&str>,
         webhook_code: &str,
     ) -> Result<FireWebhookResponse> {
         self.send_request(
             "sandbox/item/fire_webhook",
             &FireWebhookRequest {
-                client_id: &self.client_id,
-                secret: &self.secret,
+                client_id: self.client_id(),
+                secret: self.secret(),
                 access_token,
+                webhook_type,
                 webhook_code,
             },
         )
         .await
     }
+
+    /// Fire a test income webhook.
+    ///
+    /// Use the /sandbox/income/fire_webhook endpoint to manually trigger an income verification webhook in the Sandbox environment, simulating the asynchronous status transitions of an income verification.
+    ///
+    /// * `item_id` - The Item ID associated with the verification.
+    /// * `webhook` - The URL to which the webhook should be sent.
+    /// * `webhook_code` - The webhook code to send, e.g. INCOME_VERIFICATION or INCOME_VERIFICATION_RISK_SIGNALS.
+    /// * `verification_status` - The status of the income verification to report in an INCOME_VERIFICATION webhook.
+    pub async fn fire_sandbox_income_webhook(
+        &self,
+        item_id: &str,
+        webhook: &str,
+        webhook_code: &str,
+        verification_status: Option<SandboxIncomeVerificationStatus>,
+    ) -> Result<FireSandboxIncomeWebhookResponse> {
+        self.send_request(
+            "sandbox/income/fire_webhook",
+            &FireSandboxIncomeWebhookRequest {
+                client_id: self.client_id(),
+                secret: self.secret(),
+                item_id,
+                webhook,
+                webhook_code,
+                verification_status,
+            },
+        )
+        .await
+    }
+
+    /// Simulate a transfer event in Sandbox.
+    ///
+    /// Use the /sandbox/transfer/simulate endpoint to simulate a transfer event in the Sandbox environment. Note that while an event will be simulated and will appear when using endpoints such as /transfer/event/sync or /transfer/event/list, no transactions will actually take place and funds will not move between accounts, even within the Sandbox.
+    ///
+    /// * `transfer_id` - Plaid’s unique identifier for a transfer.
+    /// * `event_type` - The asynchronous event to be simulated.
+    /// * `failure_reason` - The failure reason if the event type for a transfer is "failed" or "returned".
+    pub async fn simulate_sandbox_transfer<'a>(
+        &self,
+        transfer_id: &str,
+        event_type: SandboxTransferEventType,
+        failure_reason: Option<SandboxTransferFailureReason<'a>>,
+    ) -> Result<SimulateSandboxTransferResponse> {
+        self.send_request(
+            "sandbox/transfer/simulate",
+            &SimulateSandboxTransferRequest {
+                client_id: self.client_id(),
+                secret: self.secret(),
+                transfer_id,
+                event_type,
+                failure_reason,
+            },
+        )
+        .await
+    }
+
+    /// Create a test Item and processor token.
+    ///
+    /// Use the /sandbox/processor_token/create endpoint to create a valid processor_token for an arbitrary institution ID and test credentials. The created processor_token corresponds to a new Sandbox Item. You can then use this processor_token with the /processor/ API endpoints in Sandbox. You can also use /sandbox/processor_token/create with the user_custom test username to generate a test account with custom data.
+    ///
+    /// * `institution_id` - The ID of the institution the Item will be associated with.
+    /// * `options` - An optional set of options to be used when configuring the Item.
+    pub async fn create_sandbox_processor_token<'a>(
+        &self,
+        institution_id: &str,
+        options: Option<CreateSandboxProcessorTokenOptions<'a>>,
+    ) -> Result<CreateSandboxProcessorTokenResponse> {
+        self.send_request(
+            "sandbox/processor_token/create",
+            &CreateSandboxProcessorTokenRequest {
+                client_id: self.client_id(),
+                secret: self.secret(),
+                institution_id,
+                options,
+            },
+        )
+        .await
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::client::tests::{get_test_client, SANDBOX_INSTITUTION, TEST_PRODUCTS};
+    use super::*;
+    use crate::client::tests::{
+        get_mock_client, get_test_client, SANDBOX_INSTITUTION, TEST_PRODUCTS,
+    };
+
+    #[test]
+    fn test_expected_sandbox_webhook_event() {
+        let item_id = "Ed6bjNrDLJfGvZWwnkQlfxwoNz54B5C97ejBr";
+        match expected_sandbox_webhook_event(item_id, None, "DEFAULT_UPDATE").unwrap() {
+            WebhookEvent::Transactions {
+                code,
+                item_id,
+                new_transactions,
+                ..
+            } => {
+                assert_eq!(code, WebhookCode::DefaultUpdate);
+                assert_eq!(item_id, "Ed6bjNrDLJfGvZWwnkQlfxwoNz54B5C97ejBr");
+                assert!(new_transactions.is_none());
+            }
+            event => panic!("unexpected event: {:?}", event),
+        }
+        assert!(matches!(
+            expected_sandbox_webhook_event(item_id, Some("ITEM"), "NEW_ACCOUNTS_AVAILABLE"),
+            Ok(WebhookEvent::Item { .. })
+        ));
+
+        match expected_sandbox_webhook_event(item_id, None, "FOO") {
+            Err(Error::InvalidRequest(msg)) => {
+                assert_eq!(msg, "unknown Sandbox webhook code FOO")
+            }
+            resp => panic!("unexpected response: {:?}", resp),
+        }
+        match expected_sandbox_webhook_event(item_id, Some("ITEM"), "DEFAULT_UPDATE") {
+            Err(Error::InvalidRequest(msg)) => assert_eq!(
+                msg,
+                "webhook code DEFAULT_UPDATE cannot be fired with webhook type ITEM in Sandbox"
+            ),
+            resp => panic!("unexpected response: {:?}", resp),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fire_webhook() {
+        let (client, transport) = get_mock_client();
+        for _ in 0..2 {
+            transport.push_json(
+                "sandbox/item/fire_webhook",
+                reqwest::StatusCode::OK,
+                serde_json::json!({"request_id": "1vwmF5TBQwiqfwP", "webhook_fired": true}),
+            );
+        }
+        let resp = client
+            .fire_webhook("access-sandbox-123", Some("ITEM"), "NEW_ACCOUNTS_AVAILABLE")
+            .await
+            .unwrap();
+        assert!(resp.webhook_fired);
+        client
+            .fire_webhook("access-sandbox-123", None, "DEFAULT_UPDATE")
+            .await
+            .unwrap();
+
+        let requests = transport.requests();
+        let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+        assert_eq!(body["webhook_type"], "ITEM");
+        assert_eq!(body["webhook_code"], "NEW_ACCOUNTS_AVAILABLE");
+        let body: serde_json::Value = serde_json::from_slice(&requests[1].body).unwrap();
+        assert!(body.get("webhook_type").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_fire_sandbox_income_webhook() {
+        let (client, transport) = get_mock_client();
+        transport.push_json(
+            "sandbox/income/fire_webhook",
+            reqwest::StatusCode::OK,
+            serde_json::json!({"request_id": "mdqfuVxeoza6mhu"}),
+        );
+        client
+            .fire_sandbox_income_webhook(
+                "Rn3637v1adCNj5Dl1LG6idQBzqBLwRcRZLbgM",
+                "https://webhook.example.com/plaid",
+                "INCOME_VERIFICATION",
+                Some(SandboxIncomeVerificationStatus::ProcessingComplete),
+            )
+            .await
+            .unwrap();
+
+        let body: serde_json::Value =
+            serde_json::from_slice(&transport.requests()[0].body).unwrap();
+        assert_eq!(body["item_id"], "Rn3637v1adCNj5Dl1LG6idQBzqBLwRcRZLbgM");
+        assert_eq!(body["webhook_code"], "INCOME_VERIFICATION");
+        assert_eq!(
+            body["verification_status"],
+            "VERIFICATION_STATUS_PROCESSING_COMPLETE"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_simulate_sandbox_transfer() {
+        let (client, transport) = get_mock_client();
+        transport.push_json(
+            "sandbox/transfer/simulate",
+            reqwest::StatusCode::OK,
+            serde_json::json!({"request_id": "mdqfuVxeoza6mhu"}),
+        );
+        client
+            .simulate_sandbox_transfer(
+                "460cbe92-2dcc-8eae-5ad6-b37d0ec90fd9",
+                SandboxTransferEventType::Returned,
+                Some(SandboxTransferFailureReason {
+                    ach_return_code: Some("R01"),
+                    description: "Insufficient funds",
+                }),
+            )
+            .await
+            .unwrap();
+
+        let body: serde_json::Value =
+            serde_json::from_slice(&transport.requests()[0].body).unwrap();
+        assert_eq!(body["event_type"], "returned");
+        assert_eq!(
+            body["failure_reason"],
+            serde_json::json!({"ach_return_code": "R01", "description": "Insufficient funds"})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_complete_sandbox_microdeposit_verification() {
+        let (client, transport) = get_mock_client();
+        transport.push_json(
+            "sandbox/item/set_verification_status",
+            reqwest::StatusCode::OK,
+            serde_json::json!({"request_id": "1vwmF5TBQwiqfwP"}),
+        );
+        for status in &["pending_automatic_verification", "automatically_verified"] {
+            transport.push_json(
+                "accounts/get",
+                reqwest::StatusCode::OK,
+                serde_json::json!({
+                    "request_id": "bkVE1BHWMAZ9Rnr",
+                    "accounts": [{
+                        "account_id": "BxBXxLj1m4HMXBm9WZZmCWVbPjX16EHwv99vp",
+                        "balances": {"available": null, "current": 110, "limit": null, "iso_currency_code": "USD", "unofficial_currency_code": null},
+                        "mask": "0000",
+                        "name": "Plaid Checking",
+                        "official_name": null,
+                        "type": "depository",
+                        "subtype": "checking",
+                        "verification_status": status
+                    }],
+                    "item": {
+                        "item_id": "Ed6bjNrDLJfGvZWwnkQlfxwoNz54B5C97ejBr",
+                        "institution_id": SANDBOX_INSTITUTION,
+                        "webhook": null,
+                        "error": null,
+                        "available_products": [],
+                        "billed_products": ["auth"],
+                        "consent_expiration_time": null,
+                        "update_type": "background"
+                    }
+                }),
+            );
+        }
+
+        let account = client
+            .complete_sandbox_microdeposit_verification(
+                "access-sandbox-123",
+                "BxBXxLj1m4HMXBm9WZZmCWVbPjX16EHwv99vp",
+            )
+            .await
+            .unwrap();
+        assert!(account.verification_status.unwrap().is_verified());
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 3);
+        let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+        assert_eq!(body["verification_status"], "automatically_verified");
+        let body: serde_json::Value = serde_json::from_slice(&requests[1].body).unwrap();
+        assert_eq!(
+            body["options"]["account_ids"],
+            serde_json::json!(["BxBXxLj1m4HMXBm9WZZmCWVbPjX16EHwv99vp"])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_sandbox_processor_token() {
+        let (client, transport) = get_mock_client();
+        transport.push_json(
+            "sandbox/processor_token/create",
+            reqwest::StatusCode::OK,
+            serde_json::json!({
+                "processor_token": "processor-sandbox-b0e2c4ee-a763-4df5-bfe9-46a46bce993d",
+                "request_id": "Aim3b"
+            }),
+        );
+        let resp = client
+            .create_sandbox_processor_token(
+                SANDBOX_INSTITUTION,
+                Some(CreateSandboxProcessorTokenOptions {
+                    override_username: Some("user_good"),
+                    ..Default::default()
+                }),
+            )
+            .await
+            .unwrap();
+        assert!(resp.processor_token.starts_with("processor-sandbox"));
+
+        let body: serde_json::Value =
+            serde_json::from_slice(&transport.requests()[0].body).unwrap();
+        assert_eq!(body["institution_id"], SANDBOX_INSTITUTION);
+        assert_eq!(
+            body["options"],
+            serde_json::json!({"override_username": "user_good"})
+        );
+    }
 
     #[tokio::test]
     async fn test_create_sandbox_public_token() {
         let client = get_test_client();
         let resp = client
-            .create_sandbox_public_token(SANDBOX_INSTITUTION, TEST_PRODUCTS)
+            .create_sandbox_public_token(SANDBOX_INSTITUTION, TEST_PRODUCTS, None)
+            .await
+            .unwrap();
+        assert!(resp.public_token.starts_with("public-sandbox"));
+    }
+
+    #[tokio::test]
+    async fn test_create_sandbox_public_token_with_options() {
+        let client = get_test_client();
+        let resp = client
+            .create_sandbox_public_token(
+                SANDBOX_INSTITUTION,
+                TEST_PRODUCTS,
+                Some(CreateSandboxPublicTokenOptions {
+                    webhook: Some("https://webhook-uri.com"),
+                    override_username: Some("user_good"),
+                    override_password: Some("pass_good"),
+                    transactions: Some(CreateSandboxPublicTokenTransactionsOptions {
+                        start_date: Some(NaiveDate::from_ymd_opt(2020, 1, 1).unwrap()),
+                        end_date: Some(NaiveDate::from_ymd_opt(2020, 12, 31).unwrap()),
+                    }),
+                }),
+            )
             .await
             .unwrap();
         assert!(resp.public_token.starts_with("public-sandbox"));
@@ -178,7 +758,7 @@ mod tests {
     async fn test_reset_sandbox_item() {
         let client = get_test_client();
         let sandbox_resp = client
-            .create_sandbox_public_token(SANDBOX_INSTITUTION, TEST_PRODUCTS)
+            .create_sandbox_public_token(SANDBOX_INSTITUTION, TEST_PRODUCTS, None)
             .await
             .unwrap();
         let token_resp = client
@@ -189,6 +769,8 @@ mod tests {
             .reset_sandbox_item(&token_resp.access_token)
             .await
             .unwrap();
-        assert_eq!(reset_resp.reset_login, true);
+        assert!(reset_resp.reset_login);
+        let item_resp = client.get_item(&token_resp.access_token).await.unwrap();
+        assert!(item_resp.item.requires_login());
     }
 }
@@ -0,0 +1,289 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::client::Client;
+use crate::errors::Result;
+use crate::types::Money;
+
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct SignalUserName<'a> {
+    /// The user's first name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub given_name: Option<&'a str>,
+    /// The user's middle name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub middle_name: Option<&'a str>,
+    /// The user's last name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub family_name: Option<&'a str>,
+}
+
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct SignalUserAddress<'a> {
+    /// The full street address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub street: Option<&'a str>,
+    /// The full city name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<&'a str>,
+    /// The region or state.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<&'a str>,
+    /// The postal code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub postal_code: Option<&'a str>,
+    /// The ISO 3166-1 alpha-2 country code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<&'a str>,
+}
+
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct SignalUser<'a> {
+    /// The user's legal name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<SignalUserName<'a>>,
+    /// The user's phone number, in E.164 format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<&'a str>,
+    /// The user's email address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_address: Option<&'a str>,
+    /// The user's address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<SignalUserAddress<'a>>,
+}
+
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct SignalDevice<'a> {
+    /// The IP address of the device that initiated the transaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip_address: Option<&'a str>,
+    /// The user agent of the device that initiated the transaction, e.g. "Mozilla/5.0".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_agent: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+struct EvaluateSignalRequest<'a> {
+    client_id: &'a str,
+    secret: &'a str,
+    access_token: &'a str,
+    account_id: &'a str,
+    client_transaction_id: &'a str,
+    #[cfg_attr(
+        feature = "decimal",
+        serde(with = "rust_decimal::serde::arbitrary_precision")
+    )]
+    amount: Money,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user: Option<SignalUser<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    device: Option<SignalDevice<'a>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SignalRiskScore {
+    /// A score from 1-99 that indicates the transaction return risk: a higher risk score suggests a higher return likelihood.
+    pub score: i32,
+    /// A tier from 1-5 corresponding to the score. Tier 1 has the lowest risk and tier 5 the highest.
+    pub risk_tier: Option<i32>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SignalScores {
+    /// Risk scoring details for unauthorized customer-initiated returns, e.g. R05, R07, R10.
+    pub customer_initiated_return_risk: Option<SignalRiskScore>,
+    /// Risk scoring details for bank-initiated returns, e.g. R01, R02, R03.
+    pub bank_initiated_return_risk: Option<SignalRiskScore>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct EvaluateSignalResponse {
+    /// A unique identifier for the request, which can be used for troubleshooting. This identifier, like all Plaid identifiers, is case sensitive.
+    pub request_id: String,
+    /// Risk scoring details broken down by risk category.
+    pub scores: SignalScores,
+    /// The core attributes used to compute the scores, e.g. balances and counts of recent transactions, keyed by attribute name. The set of attributes varies by account.
+    #[serde(default)]
+    pub core_attributes: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize)]
+struct ReportSignalDecisionRequest<'a> {
+    client_id: &'a str,
+    secret: &'a str,
+    client_transaction_id: &'a str,
+    initiated: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    days_funds_on_hold: Option<i32>,
+}
+
+#[derive(Serialize)]
+struct ReportSignalReturnRequest<'a> {
+    client_id: &'a str,
+    secret: &'a str,
+    client_transaction_id: &'a str,
+    return_code: &'a str,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ReportSignalResponse {
+    /// A unique identifier for the request, which can be used for troubleshooting. This identifier, like all Plaid identifiers, is case sensitive.
+    pub request_id: String,
+}
+
+impl Client {
+    /// Evaluate a planned ACH transaction.
+    ///
+    /// Use /signal/evaluate to evaluate a planned ACH transaction to get a return risk assessment (such as a risk score and risk tier) and additional risk signals.
+    ///
+    /// * `access_token` - The access token associated with the Item data is being requested for.
+    /// * `account_id` - The Plaid account_id of the account that is the funding source for the proposed transaction.
+    /// * `client_transaction_id` - The unique ID that you would like to use to refer to this transaction.
+    /// * `amount` - The transaction amount, in USD (e.g. 102.05).
+    /// * `user` - Details about the end user initiating the transaction.
+    /// * `device` - Details about the end user's device.
+    pub async fn evaluate_signal<'a>(
+        &self,
+        access_token: &str,
+        account_id: &str,
+        client_transaction_id: &str,
+        amount: Money,
+        user: Option<SignalUser<'a>>,
+        device: Option<SignalDevice<'a>>,
+    ) -> Result<EvaluateSignalResponse> {
+        self.send_request(
+            "signal/evaluate",
+            &EvaluateSignalRequest {
+                client_id: self.client_id(),
+                secret: self.secret(),
+                access_token,
+                account_id,
+                client_transaction_id,
+                amount,
+                user,
+                device,
+            },
+        )
+        .await
+    }
+
+    /// Report whether you initiated an ACH transaction.
+    ///
+    /// After calling /signal/evaluate, call /signal/decision/report to report whether the transaction was initiated. This helps improve Signal scores.
+    ///
+    /// * `client_transaction_id` - Must be the same as the client_transaction_id supplied when calling /signal/evaluate.
+    /// * `initiated` - true if the ACH transaction was initiated, false otherwise.
+    /// * `days_funds_on_hold` - The actual number of days (hold time) since the ACH debit transaction that you wait before making funds available to your customers.
+    pub async fn report_signal_decision(
+        &self,
+        client_transaction_id: &str,
+        initiated: bool,
+        days_funds_on_hold: Option<i32>,
+    ) -> Result<ReportSignalResponse> {
+        self.send_request(
+            "signal/decision/report",
+            &ReportSignalDecisionRequest {
+                client_id: self.client_id(),
+                secret: self.secret(),
+                client_transaction_id,
+                initiated,
+                days_funds_on_hold,
+            },
+        )
+        .await
+    }
+
+    /// Report a return for an ACH transaction.
+    ///
+    /// Call /signal/return/report to report a returned transaction that was previously sent to the /signal/evaluate endpoint. This helps improve Signal scores.
+    ///
+    /// * `client_transaction_id` - Must be the same as the client_transaction_id supplied when calling /signal/evaluate.
+    /// * `return_code` - Must be a valid ACH return code (e.g. "R01").
+    pub async fn report_signal_return(
+        &self,
+        client_transaction_id: &str,
+        return_code: &str,
+    ) -> Result<ReportSignalResponse> {
+        self.send_request(
+            "signal/return/report",
+            &ReportSignalReturnRequest {
+                client_id: self.client_id(),
+                secret: self.secret(),
+                client_transaction_id,
+                return_code,
+            },
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::tests::get_mock_client;
+    use reqwest::StatusCode;
+
+    #[tokio::test]
+    async fn test_evaluate_signal() {
+        let (client, transport) = get_mock_client();
+        transport.push_json(
+            "signal/evaluate",
+            StatusCode::OK,
+            serde_json::json!({
+                "request_id": "a325fa",
+                "scores": {
+                    "customer_initiated_return_risk": {"score": 9, "risk_tier": 1},
+                    "bank_initiated_return_risk": {"score": 72, "risk_tier": 7}
+                },
+                "core_attributes": {
+                    "days_since_first_plaid_connection": 510,
+                    "is_savings_or_money_market_account": false
+                }
+            }),
+        );
+        transport.push_json(
+            "signal/return/report",
+            StatusCode::OK,
+            serde_json::json!({"request_id": "mdqfuVxeoza6mhu"}),
+        );
+
+        let resp = client
+            .evaluate_signal(
+                "access-sandbox-123",
+                "3gE5gnRzNyfXpBK5wEEKcymJ5albGVUqg77gr",
+                "txn12345",
+                Money::from(123),
+                None,
+                Some(SignalDevice {
+                    ip_address: Some("198.30.2.2"),
+                    ..Default::default()
+                }),
+            )
+            .await
+            .unwrap();
+        let bank_risk = resp.scores.bank_initiated_return_risk.unwrap();
+        assert_eq!(bank_risk.score, 72);
+        assert_eq!(bank_risk.risk_tier, Some(7));
+        assert_eq!(
+            resp.core_attributes["days_since_first_plaid_connection"],
+            510
+        );
+
+        client
+            .report_signal_return("txn12345", "R01")
+            .await
+            .unwrap();
+
+        let requests = transport.requests();
+        let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+        assert_eq!(body["client_transaction_id"], "txn12345");
+        assert_eq!(
+            body["device"],
+            serde_json::json!({"ip_address": "198.30.2.2"})
+        );
+        assert!(body.get("user").is_none());
+        let body: serde_json::Value = serde_json::from_slice(&requests[1].body).unwrap();
+        assert_eq!(body["return_code"], "R01");
+    }
+}
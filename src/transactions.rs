@@ -1,12 +1,14 @@
 use chrono::{DateTime, NaiveDate, Utc};
+use futures_util::stream::{self, Stream, TryStreamExt};
 use serde::{Deserialize, Serialize};
 
-use crate::accounts::Account;
+use crate::accounts::{Account, AccountType};
 use crate::client::Client;
-use crate::errors::Result;
+use crate::errors::{Error, PlaidErrorCode, Result};
 use crate::item::Item;
+use crate::types::Money;
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Transaction {
     /// The unique ID of the transaction. Like all Plaid identifiers, the transaction_id is case sensitive.
     pub transaction_id: String,
@@ -17,14 +19,21 @@ pub struct Transaction {
     /// When true, identifies the transaction as pending or unsettled. Pending transaction details (name, type, amount, category ID) may change before they are settled.
     pub pending: bool,
     /// The channel used to make a payment.
-    /// Possible values: online, in store, other
-    pub payment_channel: String,
+    pub payment_channel: PaymentChannel,
     /// Transaction information specific to inter-bank transfers. If the transaction was not an inter-bank transfer, all fields will be null.
     pub payment_meta: PaymentMeta,
     /// The merchant name or transaction description.
     pub name: String,
+    /// The string returned by the financial institution to describe the transaction. Only returned when include_original_description is set in the request options.
+    pub original_description: Option<String>,
     /// The merchant name, as extracted by Plaid from the name field.
     pub merchant_name: Option<String>,
+    /// The URL of a logo associated with the merchant, if available. The logo will always be 100×100 pixel PNG file.
+    pub logo_url: Option<String>,
+    /// The website associated with the merchant, if available.
+    pub website: Option<String>,
+    /// The counterparties present in the transaction, such as the merchant or the financial institution.
+    pub counterparties: Option<Vec<Counterparty>>,
     /// A representation of where a transaction took place
     pub location: Location,
     /// The date that the transaction was authorized. Dates are returned in an ISO 8601 format ( YYYY-MM-DD ).
@@ -39,22 +48,147 @@ pub struct Transaction {
     pub category_id: String,
     /// A hierarchical array of the categories to which this transaction belongs
     pub category: Option<Vec<String>>,
+    /// Information describing the intent of the transaction. Only returned when include_personal_finance_category is set in the request options.
+    pub personal_finance_category: Option<PersonalFinanceCategory>,
     /// The unofficial currency code associated with the transaction.
     pub unofficial_currency_code: Option<String>,
     /// The ISO-4217 currency code of the transaction.
     pub iso_currency_code: Option<String>,
     /// The settled value of the transaction, denominated in the account's currency, as stated in iso_currency_code or unofficial_currency_code. Positive values when money moves out of the account; negative values when money moves in. For example, debit card purchases are positive; credit card payments, direct deposits, and refunds are negative.
-    pub amount: f64,
+    pub amount: Money,
     /// The ID of the account in which this transaction occurred.
     pub account_id: String,
     /// An identifier classifying the transaction type.
     /// This field is only populated for European institutions. For institutions in the US and Canada, this field is set to null.
-    /// Possible values: adjustment, atm, bank charge, bill payment, cash, cashback, cheque, direct debit, interest, purchase, standing order, transfer, null
-    pub transaction_code: Option<String>,
+    pub transaction_code: Option<TransactionCode>,
+}
+
+impl Transaction {
+    /// Returns true if money moved into the account, e.g. a direct deposit or refund. Plaid reports these with a negative amount.
+    pub fn is_credit(&self) -> bool {
+        self.amount < Money::default()
+    }
+
+    /// Returns true if money moved out of the account, e.g. a debit card purchase. Plaid reports these with a positive amount.
+    pub fn is_debit(&self) -> bool {
+        self.amount > Money::default()
+    }
+
+    /// Returns the amount with the conventional sign: positive when money moves into the account and negative when it moves out.
+    ///
+    /// This is the negation of `amount`, which follows Plaid's convention of positive values for outflows.
+    pub fn signed_amount(&self) -> Money {
+        -self.amount
+    }
+}
+
+string_enum! {
+    /// The channel used to make a payment.
+    pub enum PaymentChannel {
+        /// Transactions that took place online.
+        Online => "online",
+        /// Transactions that were made at a physical location.
+        InStore => "in store",
+        /// Transactions that relate to banks, e.g. fees or deposits.
+        Other => "other",
+        _ => Unknown,
+    }
+}
+
+string_enum! {
+    /// An identifier classifying the transaction type, only populated for European institutions.
+    pub enum TransactionCode {
+        /// Bank adjustment.
+        Adjustment => "adjustment",
+        /// Cash deposit or withdrawal via an automated teller machine.
+        Atm => "atm",
+        /// Charge or fee levied by the institution.
+        BankCharge => "bank charge",
+        /// Payment of a bill.
+        BillPayment => "bill payment",
+        /// Cash deposit or withdrawal.
+        Cash => "cash",
+        /// Cash withdrawal while making a debit card purchase.
+        Cashback => "cashback",
+        /// Document ordering the payment of money to another person or organization.
+        Cheque => "cheque",
+        /// Automatic withdrawal of funds initiated by a third party at a regular interval.
+        DirectDebit => "direct debit",
+        /// Interest earned or incurred.
+        Interest => "interest",
+        /// Purchase made with a debit or credit card.
+        Purchase => "purchase",
+        /// Payment instructed by the account holder to a third party at a regular interval.
+        StandingOrder => "standing order",
+        /// Transfer of money between accounts.
+        Transfer => "transfer",
+        _ => Unknown,
+    }
+}
+
+/// The personal finance category of a transaction, from Plaid's personal finance category taxonomy.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PersonalFinanceCategory {
+    /// A high level category that communicates the broad category of the transaction, e.g. FOOD_AND_DRINK.
+    pub primary: String,
+    /// A granular category conveying the transaction's intent, e.g. FOOD_AND_DRINK_COFFEE.
+    pub detailed: String,
+    /// A description of how confident Plaid is in the provided categorization.
+    pub confidence_level: Option<ConfidenceLevel>,
+}
+
+string_enum! {
+    /// How confident Plaid is in a personal finance category.
+    pub enum ConfidenceLevel {
+        /// Plaid is more than 98% confident in the category.
+        VeryHigh => "VERY_HIGH",
+        /// Plaid is more than 90% confident in the category.
+        High => "HIGH",
+        /// Plaid is moderately confident in the category.
+        Medium => "MEDIUM",
+        /// Plaid is not confident in the category.
+        Low => "LOW",
+        _ => Unknown,
+    }
+}
+
+/// A party involved in a transaction, such as the merchant or the financial institution.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Counterparty {
+    /// The name of the counterparty, such as the merchant or the financial institution, as extracted by Plaid from the raw description.
+    pub name: String,
+    /// The counterparty type.
+    #[serde(rename = "type")]
+    pub counterparty_type: CounterpartyType,
+    /// The URL of a logo associated with the counterparty, if available. The logo will always be 100×100 pixel PNG file.
+    pub logo_url: Option<String>,
+    /// The website associated with the counterparty.
+    pub website: Option<String>,
+    /// A description of how confident Plaid is that the counterparty is correctly identified.
+    pub confidence_level: Option<ConfidenceLevel>,
+}
+
+string_enum! {
+    /// The type of a transaction counterparty.
+    pub enum CounterpartyType {
+        /// A provider of goods or services for purchase.
+        Merchant => "merchant",
+        /// A financial entity, such as a bank, credit union or lender.
+        FinancialInstitution => "financial_institution",
+        /// A transfer or P2P app used to send money between people or businesses, e.g. Venmo or Paypal.
+        PaymentApp => "payment_app",
+        /// A marketplace that facilitates payments between buyers and sellers, e.g. Amazon Marketplace.
+        Marketplace => "marketplace",
+        /// A payment terminal or point of sale provider, e.g. Square.
+        PaymentTerminal => "payment_terminal",
+        /// The payer in an income transaction, such as an employer or client.
+        IncomeSource => "income_source",
+        _ => Unknown,
+    }
 }
 
 /// Transaction information specific to inter-bank transfers.
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct PaymentMeta {
     /// The transaction reference number supplied by the financial institution.
     pub reference_number: Option<String>,
@@ -74,7 +208,7 @@ pub struct PaymentMeta {
     pub reason: Option<String>,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Location {
     /// The street address where the transaction occurred.
     pub address: Option<String>,
@@ -94,22 +228,44 @@ pub struct Location {
     pub store_number: Option<String>,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct AccountBalances {
     /// The amount of funds available to be withdrawn from the account, as determined by the financial institution.
-    pub available: Option<f64>,
+    pub available: Option<Money>,
     /// The total amount of funds in or owed by the account.
-    pub current: f64,
+    pub current: Money,
     /// For credit-type accounts, this represents the credit limit.
     /// For depository-type accounts, this represents the pre-arranged overdraft limit, which is common for current (checking) accounts in Europe.
     /// In North America, this field is typically only available for credit-type accounts.
-    pub limit: Option<f64>,
+    pub limit: Option<Money>,
     /// The ISO-4217 currency code of the balance. Always null if unofficial_currency_code is non-null.
     pub iso_currency_code: Option<String>,
     /// The unofficial currency code associated with the balance. Always null if iso_currency_code is non-null.
     pub unofficial_currency_code: Option<String>,
 }
 
+/// The maximum number of transactions /transactions/get returns per call, also used as the page size when paginating.
+const TRANSACTIONS_PAGE_SIZE: i32 = 500;
+
+/// How many times `sync_all_transactions` paginates from the start cursor before giving up on TRANSACTIONS_SYNC_MUTATION_DURING_PAGINATION.
+const SYNC_MUTATION_RESTART_ATTEMPTS: u32 = 5;
+/// The delay before `sync_all_transactions` restarts pagination after TRANSACTIONS_SYNC_MUTATION_DURING_PAGINATION.
+const SYNC_MUTATION_RESTART_DELAY: std::time::Duration = std::time::Duration::from_millis(500);
+
+/// Checks that a date range is not reversed before it is sent to Plaid.
+pub(crate) fn validate_date_range(start_date: NaiveDate, end_date: NaiveDate) -> Result<()> {
+    if start_date > end_date {
+        return Err(Error::InvalidRequest(format!(
+            "start_date {} is after end_date {}",
+            start_date, end_date
+        )));
+    }
+    Ok(())
+}
+
+/// The number of transactions /transactions/get returns when count is not specified.
+const TRANSACTIONS_DEFAULT_COUNT: i32 = 100;
+
 #[derive(Serialize)]
 struct GetTransactionsRequest<'a> {
     client_id: &'a str,
@@ -129,9 +285,89 @@ pub struct GetTransactionsOptions<'a> {
     pub count: i32,
     /// The number of transactions to skip. The default value is 0.
     pub offset: i32,
+    /// Include the personal_finance_category object in the response.
+    pub include_personal_finance_category: bool,
+    /// Include the raw unparsed transaction description from the financial institution in the original_description field.
+    pub include_original_description: bool,
+}
+
+impl Default for GetTransactionsOptions<'_> {
+    fn default() -> Self {
+        Self {
+            account_ids: None,
+            count: TRANSACTIONS_DEFAULT_COUNT,
+            offset: 0,
+            include_personal_finance_category: false,
+            include_original_description: false,
+        }
+    }
+}
+
+impl<'a> GetTransactionsOptions<'a> {
+    /// Returns a builder starting from the default options.
+    pub fn builder() -> GetTransactionsOptionsBuilder<'a> {
+        GetTransactionsOptionsBuilder {
+            options: Default::default(),
+        }
+    }
+}
+
+/// Builder for [`GetTransactionsOptions`] that checks count and offset are within the range accepted by Plaid.
+#[derive(Debug, Clone)]
+pub struct GetTransactionsOptionsBuilder<'a> {
+    options: GetTransactionsOptions<'a>,
+}
+
+impl<'a> GetTransactionsOptionsBuilder<'a> {
+    /// A list of account_ids to retrieve for the Item.
+    pub fn account_ids(mut self, account_ids: &'a [&'a str]) -> Self {
+        self.options.account_ids = Some(account_ids);
+        self
+    }
+
+    /// The number of transactions to fetch, between 1 and 500.
+    pub fn count(mut self, count: i32) -> Self {
+        self.options.count = count;
+        self
+    }
+
+    /// The number of transactions to skip.
+    pub fn offset(mut self, offset: i32) -> Self {
+        self.options.offset = offset;
+        self
+    }
+
+    /// Include the personal_finance_category object in the response.
+    pub fn include_personal_finance_category(mut self, include: bool) -> Self {
+        self.options.include_personal_finance_category = include;
+        self
+    }
+
+    /// Include the raw transaction description in the original_description field.
+    pub fn include_original_description(mut self, include: bool) -> Self {
+        self.options.include_original_description = include;
+        self
+    }
+
+    /// Validates and returns the options.
+    pub fn build(self) -> Result<GetTransactionsOptions<'a>> {
+        if !(1..=TRANSACTIONS_PAGE_SIZE).contains(&self.options.count) {
+            return Err(Error::InvalidRequest(format!(
+                "count must be between 1 and {}, got {}",
+                TRANSACTIONS_PAGE_SIZE, self.options.count
+            )));
+        }
+        if self.options.offset < 0 {
+            return Err(Error::InvalidRequest(format!(
+                "offset must not be negative, got {}",
+                self.options.offset
+            )));
+        }
+        Ok(self.options)
+    }
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct GetTransactionsResponse {
     /// A unique identifier for the request, which can be used for troubleshooting. This identifier, like all Plaid identifiers, is case sensitive.
     pub request_id: String,
@@ -145,6 +381,197 @@ pub struct GetTransactionsResponse {
     pub item: Item,
 }
 
+impl GetTransactionsResponse {
+    /// Returns the account a transaction belongs to, or None if its account_id is not in `accounts`.
+    ///
+    /// * `transaction` - A transaction, usually one from this response.
+    pub fn account_for(&self, transaction: &Transaction) -> Option<&Account> {
+        self.accounts
+            .iter()
+            .find(|account| account.account_id == transaction.account_id)
+    }
+
+    /// Returns the transactions whose account_id is not in `accounts`. This should always be empty, so any transactions returned point to an incomplete response.
+    pub fn unmatched_transactions(&self) -> Vec<&Transaction> {
+        self.transactions
+            .iter()
+            .filter(|transaction| self.account_for(transaction).is_none())
+            .collect()
+    }
+}
+
+/// A /transactions/sync cursor, marking the position in an Item's transaction history up to which updates have been received.
+///
+/// Serializes as a plain string, so it can be persisted as is and passed to the next sync. An empty cursor, like passing `None`, starts a full historical sync.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[serde(transparent)]
+pub struct Cursor(pub String);
+
+impl Cursor {
+    /// Returns the cursor as a string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns true for the empty cursor, which starts a full historical sync.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl From<String> for Cursor {
+    fn from(cursor: String) -> Self {
+        Cursor(cursor)
+    }
+}
+
+impl From<&str> for Cursor {
+    fn from(cursor: &str) -> Self {
+        Cursor(cursor.to_string())
+    }
+}
+
+impl std::fmt::Display for Cursor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[derive(Serialize)]
+struct SyncTransactionsRequest<'a> {
+    client_id: &'a str,
+    secret: &'a str,
+    access_token: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cursor: Option<&'a Cursor>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    count: Option<i32>,
+}
+
+/// A transaction that has been removed since the previous sync.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RemovedTransaction {
+    /// The ID of the removed transaction.
+    pub transaction_id: String,
+    /// The ID of the account the removed transaction belonged to.
+    pub account_id: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SyncTransactionsResponse {
+    /// A unique identifier for the request, which can be used for troubleshooting. This identifier, like all Plaid identifiers, is case sensitive.
+    pub request_id: String,
+    /// Transactions that have been added to the Item since the provided cursor.
+    pub added: Vec<Transaction>,
+    /// Transactions that have been modified on the Item since the provided cursor.
+    pub modified: Vec<Transaction>,
+    /// Transactions that have been removed from the Item since the provided cursor.
+    pub removed: Vec<RemovedTransaction>,
+    /// Cursor used for fetching any future updates after the latest update provided in this response.
+    pub next_cursor: Cursor,
+    /// Represents if more than requested count of transaction updates exist. If true, the additional updates can be fetched by making an additional request with cursor set to next_cursor.
+    pub has_more: bool,
+}
+
+#[derive(Serialize)]
+struct GetRecurringTransactionsRequest<'a> {
+    client_id: &'a str,
+    secret: &'a str,
+    access_token: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    account_ids: Option<&'a [&'a str]>,
+}
+
+/// An amount of a recurring transaction stream.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TransactionStreamAmount {
+    /// The monetary amount of the transaction.
+    pub amount: Option<Money>,
+    /// The ISO-4217 currency code of the amount. Always null if unofficial_currency_code is non-null.
+    pub iso_currency_code: Option<String>,
+    /// The unofficial currency code of the amount. Always null if iso_currency_code is non-null.
+    pub unofficial_currency_code: Option<String>,
+}
+
+/// A grouping of related transactions that occur on a recurring basis.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TransactionStream {
+    /// The ID of the account to which the stream belongs.
+    pub account_id: String,
+    /// A unique ID for the stream.
+    pub stream_id: String,
+    /// A hierarchical array of the categories to which this transaction stream belongs.
+    pub category: Option<Vec<String>>,
+    /// The ID of the category to which this transaction stream belongs.
+    pub category_id: Option<String>,
+    /// A description of the transaction stream.
+    pub description: String,
+    /// The merchant associated with the transaction stream.
+    pub merchant_name: Option<String>,
+    /// The posted date of the earliest transaction in the stream.
+    pub first_date: NaiveDate,
+    /// The posted date of the latest transaction in the stream.
+    pub last_date: NaiveDate,
+    /// Describes the frequency of the transaction stream.
+    pub frequency: TransactionStreamFrequency,
+    /// An array of Plaid transaction IDs belonging to the stream, sorted by posted date.
+    pub transaction_ids: Vec<String>,
+    /// The average amount of the transactions in the stream.
+    pub average_amount: TransactionStreamAmount,
+    /// The amount of the most recent transaction in the stream.
+    pub last_amount: Option<TransactionStreamAmount>,
+    /// Indicates whether the transaction stream is still live.
+    pub is_active: bool,
+    /// The current status of the transaction stream.
+    pub status: Option<TransactionStreamStatus>,
+}
+
+string_enum! {
+    /// How often the transactions in a recurring stream occur.
+    pub enum TransactionStreamFrequency {
+        /// Assigned to a stream of transactions that occur approximately every week.
+        Weekly => "WEEKLY",
+        /// Assigned to a stream of transactions that occur approximately every 2 weeks.
+        Biweekly => "BIWEEKLY",
+        /// Assigned to a stream of transactions that occur approximately twice per month.
+        SemiMonthly => "SEMI_MONTHLY",
+        /// Assigned to a stream of transactions that occur approximately every month.
+        Monthly => "MONTHLY",
+        /// Assigned to a stream of transactions that occur approximately every year.
+        Annually => "ANNUALLY",
+        /// Assigned to a stream of transactions that do not fit any of the pre-defined frequencies.
+        Unknown => "UNKNOWN",
+        _ => Other,
+    }
+}
+
+string_enum! {
+    /// The status of a recurring transaction stream.
+    pub enum TransactionStreamStatus {
+        /// A stream whose status Plaid could not determine.
+        Unknown => "UNKNOWN",
+        /// A stream with at least three transactions whose intervals and amounts are consistent.
+        Mature => "MATURE",
+        /// A newly detected stream with fewer transactions, which may become mature or be removed.
+        EarlyDetection => "EARLY_DETECTION",
+        /// A stream that was previously detected but no longer matches recurring behavior.
+        Tombstoned => "TOMBSTONED",
+        _ => Other,
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GetRecurringTransactionsResponse {
+    /// A unique identifier for the request, which can be used for troubleshooting. This identifier, like all Plaid identifiers, is case sensitive.
+    pub request_id: String,
+    /// An array of depository transaction streams.
+    pub inflow_streams: Vec<TransactionStream>,
+    /// An array of expense transaction streams.
+    pub outflow_streams: Vec<TransactionStream>,
+    /// Timestamp in ISO 8601 format indicating the last time transaction streams for the given account were updated on.
+    pub updated_datetime: Option<DateTime<Utc>>,
+}
+
 #[derive(Serialize)]
 struct RefreshTransactionsRequest<'a> {
     client_id: &'a str,
@@ -152,12 +579,90 @@ struct RefreshTransactionsRequest<'a> {
     access_token: &'a str,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct RefreshTransactionsResponse {
     /// A unique identifier for the request, which can be used for troubleshooting. This identifier, like all Plaid identifiers, is case sensitive.
     pub request_id: String,
 }
 
+string_enum! {
+    /// The direction in which money moved for a transaction sent to /transactions/enrich.
+    pub enum EnrichTransactionDirection {
+        /// Money moved into the account.
+        Inflow => "INFLOW",
+        /// Money moved out of the account.
+        Outflow => "OUTFLOW",
+        _ => Other,
+    }
+}
+
+/// A transaction from your own records to be enriched by /transactions/enrich.
+#[derive(Serialize, Debug, Clone)]
+pub struct EnrichTransaction<'a> {
+    /// A unique ID for the transaction used to help you tie data back to your systems.
+    pub id: &'a str,
+    /// The raw description of the transaction.
+    pub description: &'a str,
+    /// The absolute value of the transaction (>= 0).
+    #[cfg_attr(
+        feature = "decimal",
+        serde(with = "rust_decimal::serde::arbitrary_precision")
+    )]
+    pub amount: Money,
+    /// The direction of the transaction from the end user's account perspective.
+    pub direction: EnrichTransactionDirection,
+    /// The ISO-4217 currency code of the transaction, e.g. USD.
+    pub iso_currency_code: &'a str,
+}
+
+#[derive(Serialize)]
+struct EnrichTransactionsRequest<'a> {
+    client_id: &'a str,
+    secret: &'a str,
+    account_type: AccountType,
+    transactions: &'a [EnrichTransaction<'a>],
+}
+
+/// The enrichments Plaid derived for a transaction.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TransactionEnrichments {
+    /// The name of the primary counterparty, such as the merchant or the financial institution, as extracted by Plaid from the raw description.
+    pub merchant_name: Option<String>,
+    /// Information describing the intent of the transaction.
+    pub personal_finance_category: Option<PersonalFinanceCategory>,
+    /// The URL of a logo associated with the merchant, if available. The logo will always be 100×100 pixel PNG file.
+    pub logo_url: Option<String>,
+    /// The website associated with the merchant.
+    pub website: Option<String>,
+    /// The channel used to make a payment.
+    pub payment_channel: Option<PaymentChannel>,
+}
+
+/// A transaction returned by /transactions/enrich.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct EnrichedTransaction {
+    /// The unique ID for the transaction as provided in the request.
+    pub id: String,
+    /// The raw description of the transaction.
+    pub description: String,
+    /// The absolute value of the transaction (>= 0).
+    pub amount: Money,
+    /// The direction of the transaction from the end user's account perspective.
+    pub direction: Option<EnrichTransactionDirection>,
+    /// The ISO-4217 currency code of the transaction.
+    pub iso_currency_code: String,
+    /// The enrichments Plaid derived for the transaction.
+    pub enrichments: TransactionEnrichments,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct EnrichTransactionsResponse {
+    /// A unique identifier for the request, which can be used for troubleshooting. This identifier, like all Plaid identifiers, is case sensitive.
+    pub request_id: String,
+    /// A list of enriched transactions, in the same order as the request.
+    pub enriched_transactions: Vec<EnrichedTransaction>,
+}
+
 impl Client {
     /// Get transaction data.
     ///
@@ -180,11 +685,12 @@ impl Client {
         end_date: NaiveDate,
         options: Option<GetTransactionsOptions<'a>>,
     ) -> Result<GetTransactionsResponse> {
+        validate_date_range(start_date, end_date)?;
         self.send_request(
             "transactions/get",
             &GetTransactionsRequest {
-                client_id: &self.client_id,
-                secret: &self.secret,
+                client_id: self.client_id(),
+                secret: self.secret(),
                 access_token,
                 start_date,
                 end_date,
@@ -194,6 +700,180 @@ impl Client {
         .await
     }
 
+    /// Get all transaction data in a date range.
+    ///
+    /// Repeatedly calls /transactions/get, paging through the results until every transaction in the date range has been fetched. The returned response contains the transactions from all pages, along with the accounts and Item from the last page.
+    ///
+    /// * `access_token` - The access token associated with the Item data is being requested for.
+    /// * `start_date` - The earliest date for which data should be returned.
+    /// * `end_date` - The latest date for which data should be returned.
+    /// * `options` - An optional object to be used with the request. count and offset are managed by the helper; the other options are honored.
+    pub async fn get_all_transactions<'a>(
+        &self,
+        access_token: &str,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+        options: Option<GetTransactionsOptions<'a>>,
+    ) -> Result<GetTransactionsResponse> {
+        let pages = self.transaction_pages(access_token, start_date, end_date, options);
+        futures_util::pin_mut!(pages);
+        let mut transactions = Vec::new();
+        let mut last_page = None;
+        while let Some(mut page) = pages.try_next().await? {
+            transactions.append(&mut page.transactions);
+            last_page = Some(page);
+        }
+        let mut resp = last_page.expect("transaction_pages yields at least one page or an error");
+        resp.transactions = transactions;
+        Ok(resp)
+    }
+
+    /// Stream all transaction data in a date range.
+    ///
+    /// Like `get_all_transactions`, but pages through /transactions/get lazily as the stream is polled, so only one page of transactions is held in memory at a time. If a request fails, the error is yielded and the stream ends.
+    ///
+    /// * `access_token` - The access token associated with the Item data is being requested for.
+    /// * `start_date` - The earliest date for which data should be returned.
+    /// * `end_date` - The latest date for which data should be returned.
+    /// * `options` - An optional object to be used with the request. count and offset are managed by the stream; the other options are honored.
+    pub fn stream_transactions<'a>(
+        &'a self,
+        access_token: &'a str,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+        options: Option<GetTransactionsOptions<'a>>,
+    ) -> impl Stream<Item = Result<Transaction>> + 'a {
+        self.transaction_pages(access_token, start_date, end_date, options)
+            .map_ok(|page| stream::iter(page.transactions.into_iter().map(Ok)))
+            .try_flatten()
+    }
+
+    /// Pages through /transactions/get, yielding each response until every transaction in the date range has been fetched. Shared by `get_all_transactions` and `stream_transactions`.
+    fn transaction_pages<'a>(
+        &'a self,
+        access_token: &'a str,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+        options: Option<GetTransactionsOptions<'a>>,
+    ) -> impl Stream<Item = Result<GetTransactionsResponse>> + 'a {
+        let first_page = GetTransactionsOptions {
+            count: TRANSACTIONS_PAGE_SIZE,
+            offset: 0,
+            ..options.unwrap_or_default()
+        };
+        stream::try_unfold(Some(first_page), move |page| async move {
+            let mut page = match page {
+                Some(page) => page,
+                None => return Ok(None),
+            };
+            let resp = self
+                .get_transactions(access_token, start_date, end_date, Some(page.clone()))
+                .await?;
+            page.offset += resp.transactions.len() as i32;
+            let done = resp.transactions.is_empty() || page.offset >= resp.total_transactions;
+            Ok(Some((resp, if done { None } else { Some(page) })))
+        })
+    }
+
+    /// Get incremental transaction updates on an Item.
+    ///
+    /// The /transactions/sync endpoint returns the transactions added, modified and removed on an Item since the provided cursor. Persist next_cursor and pass it to the following call to receive only new updates. When has_more is true, call the endpoint again with next_cursor to fetch the remaining updates.
+    ///
+    /// * `access_token` - The access token associated with the Item data is being requested for.
+    /// * `cursor` - The cursor value returned by the previous call. Pass `None` or an empty cursor to start a full historical sync.
+    /// * `count` - The number of transaction updates to fetch. Maximum: 500
+    pub async fn sync_transactions(
+        &self,
+        access_token: &str,
+        cursor: Option<&Cursor>,
+        count: Option<i32>,
+    ) -> Result<SyncTransactionsResponse> {
+        self.send_request(
+            "transactions/sync",
+            &SyncTransactionsRequest {
+                client_id: self.client_id(),
+                secret: self.secret(),
+                access_token,
+                cursor,
+                count,
+            },
+        )
+        .await
+    }
+
+    /// Get all incremental transaction updates on an Item.
+    ///
+    /// Repeatedly calls /transactions/sync until has_more is false, accumulating the added, modified and removed transactions from every page. If Plaid reports TRANSACTIONS_SYNC_MUTATION_DURING_PAGINATION, the accumulated updates are discarded and pagination restarts from `start_cursor`, up to 5 times in total before the error is returned. The returned response contains the merged updates and the final next_cursor, which should be persisted for the next call.
+    ///
+    /// * `access_token` - The access token associated with the Item data is being requested for.
+    /// * `start_cursor` - The cursor value returned by the previous sync. Pass `None` or an empty cursor to start a full historical sync.
+    pub async fn sync_all_transactions(
+        &self,
+        access_token: &str,
+        start_cursor: Option<&Cursor>,
+    ) -> Result<SyncTransactionsResponse> {
+        let mut attempt = 0;
+        'restart: loop {
+            let mut added = Vec::new();
+            let mut modified = Vec::new();
+            let mut removed = Vec::new();
+            let mut cursor = start_cursor.cloned();
+            loop {
+                let mut resp = match self
+                    .sync_transactions(access_token, cursor.as_ref(), Some(TRANSACTIONS_PAGE_SIZE))
+                    .await
+                {
+                    Ok(resp) => resp,
+                    Err(Error::Plaid(err))
+                        if err.code()
+                            == PlaidErrorCode::TransactionsSyncMutationDuringPagination =>
+                    {
+                        attempt += 1;
+                        if attempt >= SYNC_MUTATION_RESTART_ATTEMPTS {
+                            return Err(Error::Plaid(err));
+                        }
+                        tokio::time::sleep(SYNC_MUTATION_RESTART_DELAY).await;
+                        continue 'restart;
+                    }
+                    Err(err) => return Err(err),
+                };
+                added.append(&mut resp.added);
+                modified.append(&mut resp.modified);
+                removed.append(&mut resp.removed);
+                if !resp.has_more {
+                    resp.added = added;
+                    resp.modified = modified;
+                    resp.removed = removed;
+                    return Ok(resp);
+                }
+                cursor = Some(resp.next_cursor);
+            }
+        }
+    }
+
+    /// Fetch recurring transaction streams.
+    ///
+    /// The /transactions/recurring/get endpoint identifies and returns the recurring transaction streams of an Item, such as subscriptions and paychecks. Streams are split into inflow_streams for money coming into the account and outflow_streams for money leaving it.
+    ///
+    /// * `access_token` - The access token associated with the Item data is being requested for.
+    /// * `account_ids` - An optional list of account_ids to retrieve streams for.
+    pub async fn get_recurring_transactions(
+        &self,
+        access_token: &str,
+        account_ids: Option<&[&str]>,
+    ) -> Result<GetRecurringTransactionsResponse> {
+        self.send_request(
+            "transactions/recurring/get",
+            &GetRecurringTransactionsRequest {
+                client_id: self.client_id(),
+                secret: self.secret(),
+                access_token,
+                account_ids,
+            },
+        )
+        .await
+    }
+
     /// Refresh transaction data.
     ///
     /// /transactions/refresh is an optional endpoint for users of the Transactions product. It initiates an on-demand extraction to fetch the newest transactions for an Item. This on-demand extraction takes place in addition to the periodic extractions that automatically occur multiple times a day for any Transactions-enabled Item. If changes to transactions are discovered after calling /transactions/refresh, Plaid will fire a webhook: TRANSACTIONS_REMOVED will be fired if any removed transactions are detected, and DEFAULT_UPDATE will be fired if any new transactions are detected. New transactions can be fetched by calling /transactions/get.
@@ -206,13 +886,36 @@ impl Client {
         self.send_request(
             "transactions/refresh",
             &RefreshTransactionsRequest {
-                client_id: &self.client_id,
-                secret: &self.secret,
+                client_id: self.client_id(),
+                secret: self.secret(),
                 access_token,
             },
         )
         .await
     }
+
+    /// Enrich locally-held transaction data.
+    ///
+    /// The /transactions/enrich endpoint enriches raw transaction data generated by your own banking products or retrieved from other non-Plaid sources with merchant names, personal finance categories, logos and websites.
+    ///
+    /// * `account_type` - The account type for the requested transactions, either depository or credit.
+    /// * `transactions` - An array of transaction objects to be enriched by Plaid. Maximum of 100 transactions per request.
+    pub async fn enrich_transactions<'a>(
+        &self,
+        account_type: AccountType,
+        transactions: &[EnrichTransaction<'a>],
+    ) -> Result<EnrichTransactionsResponse> {
+        self.send_request(
+            "transactions/enrich",
+            &EnrichTransactionsRequest {
+                client_id: self.client_id(),
+                secret: self.secret(),
+                account_type,
+                transactions,
+            },
+        )
+        .await
+    }
 }
 
 #[cfg(test)]
@@ -220,14 +923,467 @@ mod tests {
     use std::ops::Sub;
 
     use super::*;
-    use crate::client::tests::{get_test_client, SANDBOX_INSTITUTION, TEST_PRODUCTS};
+    use crate::client::tests::{
+        get_mock_client, get_test_client, SANDBOX_INSTITUTION, TEST_PRODUCTS,
+    };
     use crate::errors::Error;
 
+    #[tokio::test]
+    async fn test_get_transactions_invalid_date_range() {
+        let client = Client::new(
+            "client_id".to_string(),
+            "secret".to_string(),
+            crate::client::Environment::Sandbox,
+        );
+        let start = NaiveDate::from_ymd_opt(2021, 2, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2021, 1, 1).unwrap();
+        match client
+            .get_transactions("access_token", start, end, None)
+            .await
+        {
+            Err(Error::InvalidRequest(msg)) => {
+                assert_eq!(msg, "start_date 2021-02-01 is after end_date 2021-01-01")
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+        match client
+            .get_investment_transactions("access_token", start, end, None)
+            .await
+        {
+            Err(Error::InvalidRequest(_)) => {}
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_get_transactions_options_builder() {
+        let options = GetTransactionsOptions::default();
+        assert_eq!(options.count, 100);
+        assert_eq!(options.offset, 0);
+        assert!(options.account_ids.is_none());
+
+        let options = GetTransactionsOptions::builder()
+            .account_ids(&["account_id"])
+            .count(500)
+            .offset(20)
+            .include_personal_finance_category(true)
+            .include_original_description(true)
+            .build()
+            .unwrap();
+        assert_eq!(options.account_ids, Some(&["account_id"][..]));
+        assert_eq!(options.count, 500);
+        assert_eq!(options.offset, 20);
+        assert!(options.include_personal_finance_category);
+        assert!(options.include_original_description);
+
+        for builder in [
+            GetTransactionsOptions::builder().count(501),
+            GetTransactionsOptions::builder().count(0),
+            GetTransactionsOptions::builder().offset(-1),
+        ] {
+            match builder.build() {
+                Err(Error::InvalidRequest(_)) => {}
+                other => panic!("unexpected result: {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_payment_channel_and_transaction_code() {
+        assert_eq!(PaymentChannel::from("in store"), PaymentChannel::InStore);
+        assert_eq!(PaymentChannel::InStore.as_str(), "in store");
+        assert_eq!(
+            PaymentChannel::from("by mail"),
+            PaymentChannel::Unknown("by mail".to_string())
+        );
+        let code: Option<TransactionCode> =
+            serde_json::from_value(serde_json::json!("standing order")).unwrap();
+        assert_eq!(code, Some(TransactionCode::StandingOrder));
+        let code: Option<TransactionCode> =
+            serde_json::from_value(serde_json::Value::Null).unwrap();
+        assert!(code.is_none());
+    }
+
+    fn transaction_json(transaction_id: &str, amount: i32) -> serde_json::Value {
+        serde_json::json!({
+            "transaction_id": transaction_id,
+            "account_owner": null,
+            "pending_transaction_id": null,
+            "pending": false,
+            "payment_channel": "in store",
+            "payment_meta": {},
+            "name": "Apple Store",
+            "merchant_name": "Apple",
+            "location": {},
+            "authorized_date": null,
+            "authorized_datetime": null,
+            "date": "2021-02-15",
+            "datetime": null,
+            "category_id": "19013000",
+            "category": ["Shops", "Computers and Electronics"],
+            "unofficial_currency_code": null,
+            "iso_currency_code": "USD",
+            "amount": amount,
+            "account_id": "BxBXxLj1m4HMXBm9WZZmCWVbPjX16EHwv99vp",
+            "transaction_code": null
+        })
+    }
+
+    #[test]
+    fn test_signed_amount() {
+        let transaction = |amount: i32| -> Transaction {
+            serde_json::from_value(transaction_json(
+                "lPNjeW1nR6CDn5okmGQ6hEpMo4lLNoSrzqDje",
+                amount,
+            ))
+            .unwrap()
+        };
+
+        let purchase = transaction(2307);
+        assert!(purchase.is_debit());
+        assert!(!purchase.is_credit());
+        assert_eq!(purchase.signed_amount(), Money::from(-2307));
+
+        let deposit = transaction(-500);
+        assert!(deposit.is_credit());
+        assert!(!deposit.is_debit());
+        assert_eq!(deposit.signed_amount(), Money::from(500));
+    }
+
+    #[test]
+    fn test_unmatched_transactions() {
+        let mut orphan = transaction_json("4Q3Mj8h1byIwQRxM8xGqFpwE5x1JKMcQNqxRa", 500);
+        orphan["account_id"] = "removed".into();
+        let resp: GetTransactionsResponse = serde_json::from_value(serde_json::json!({
+            "request_id": "45QSn",
+            "accounts": [{
+                "account_id": "BxBXxLj1m4HMXBm9WZZmCWVbPjX16EHwv99vp",
+                "balances": {"available": 100, "current": 110, "limit": null, "iso_currency_code": "USD", "unofficial_currency_code": null},
+                "mask": "0000",
+                "name": "Plaid Checking",
+                "official_name": null,
+                "type": "depository",
+                "subtype": "checking"
+            }],
+            "transactions": [
+                transaction_json("lPNjeW1nR6CDn5okmGQ6hEpMo4lLNoSrzqDje", 2307),
+                orphan,
+            ],
+            "total_transactions": 2,
+            "item": {
+                "item_id": "Ed6bjNrDLJfGvZWwnkQlfxwoNz54B5C97ejBr",
+                "update_type": "background"
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(
+            resp.account_for(&resp.transactions[0]).unwrap().name,
+            "Plaid Checking"
+        );
+        assert!(resp.account_for(&resp.transactions[1]).is_none());
+        let unmatched = resp.unmatched_transactions();
+        assert_eq!(unmatched.len(), 1);
+        assert_eq!(
+            unmatched[0].transaction_id,
+            "4Q3Mj8h1byIwQRxM8xGqFpwE5x1JKMcQNqxRa"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_stream_transactions() {
+        use futures_util::StreamExt;
+
+        let (client, transport) = get_mock_client();
+        let page = |transactions: Vec<serde_json::Value>| {
+            serde_json::json!({
+                "request_id": "45QSn",
+                "accounts": [],
+                "transactions": transactions,
+                "total_transactions": 3,
+                "item": {
+                    "item_id": "Ed6bjNrDLJfGvZWwnkQlfxwoNz54B5C97ejBr",
+                    "institution_id": "ins_109508",
+                    "webhook": null,
+                    "error": null,
+                    "available_products": [],
+                    "billed_products": ["transactions"],
+                    "consent_expiration_time": null,
+                    "update_type": "background"
+                }
+            })
+        };
+        transport.push_json(
+            "transactions/get",
+            reqwest::StatusCode::OK,
+            page(vec![transaction_json("t1", 10), transaction_json("t2", 20)]),
+        );
+        transport.push_json(
+            "transactions/get",
+            reqwest::StatusCode::OK,
+            page(vec![transaction_json("t3", 30)]),
+        );
+
+        let start_date = NaiveDate::from_ymd_opt(2021, 1, 1).unwrap();
+        let end_date = NaiveDate::from_ymd_opt(2021, 3, 1).unwrap();
+        let ids: Vec<String> = client
+            .stream_transactions("access-sandbox-123", start_date, end_date, None)
+            .map(|transaction| transaction.unwrap().transaction_id)
+            .collect()
+            .await;
+        assert_eq!(ids, vec!["t1", "t2", "t3"]);
+        let offsets: Vec<serde_json::Value> = transport
+            .requests()
+            .iter()
+            .map(|req| {
+                serde_json::from_slice::<serde_json::Value>(&req.body).unwrap()["options"]["offset"]
+                    .clone()
+            })
+            .collect();
+        assert_eq!(offsets, vec![serde_json::json!(0), serde_json::json!(2)]);
+
+        // The second page has no queued response, so the stream yields the error and ends.
+        transport.push_json(
+            "transactions/get",
+            reqwest::StatusCode::OK,
+            page(vec![transaction_json("t1", 10), transaction_json("t2", 20)]),
+        );
+        let results: Vec<Result<Transaction>> = client
+            .stream_transactions("access-sandbox-123", start_date, end_date, None)
+            .collect()
+            .await;
+        assert_eq!(results.len(), 3);
+        assert!(results[2].is_err());
+
+        // get_all_transactions collects the same pages.
+        transport.push_json(
+            "transactions/get",
+            reqwest::StatusCode::OK,
+            page(vec![transaction_json("t1", 10), transaction_json("t2", 20)]),
+        );
+        transport.push_json(
+            "transactions/get",
+            reqwest::StatusCode::OK,
+            page(vec![transaction_json("t3", 30)]),
+        );
+        let resp = client
+            .get_all_transactions("access-sandbox-123", start_date, end_date, None)
+            .await
+            .unwrap();
+        let ids: Vec<&str> = resp
+            .transactions
+            .iter()
+            .map(|transaction| transaction.transaction_id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["t1", "t2", "t3"]);
+        assert_eq!(resp.total_transactions, 3);
+    }
+
+    #[tokio::test]
+    async fn test_enrich_transactions() {
+        let (client, transport) = get_mock_client();
+        transport.push_json(
+            "transactions/enrich",
+            reqwest::StatusCode::OK,
+            serde_json::json!({
+                "request_id": "Wvhy9PZHQLV8njG",
+                "enriched_transactions": [{
+                    "id": "6135818adda16500147e7c1d",
+                    "description": "PURCHASE WM SUPERCENTER #1700",
+                    "amount": 72.1,
+                    "direction": "OUTFLOW",
+                    "iso_currency_code": "USD",
+                    "enrichments": {
+                        "merchant_name": "Walmart",
+                        "personal_finance_category": {
+                            "primary": "GENERAL_MERCHANDISE",
+                            "detailed": "GENERAL_MERCHANDISE_SUPERSTORES",
+                            "confidence_level": "VERY_HIGH"
+                        },
+                        "logo_url": "https://plaid-merchant-logos.plaid.com/walmart_1100.png",
+                        "website": "walmart.com",
+                        "payment_channel": "in store"
+                    }
+                }]
+            }),
+        );
+
+        let resp = client
+            .enrich_transactions(
+                AccountType::Depository,
+                &[EnrichTransaction {
+                    id: "6135818adda16500147e7c1d",
+                    description: "PURCHASE WM SUPERCENTER #1700",
+                    amount: Money::from(72),
+                    direction: EnrichTransactionDirection::Outflow,
+                    iso_currency_code: "USD",
+                }],
+            )
+            .await
+            .unwrap();
+        let enrichments = &resp.enriched_transactions[0].enrichments;
+        assert_eq!(enrichments.merchant_name.as_deref(), Some("Walmart"));
+        assert_eq!(
+            enrichments
+                .personal_finance_category
+                .as_ref()
+                .unwrap()
+                .primary,
+            "GENERAL_MERCHANDISE"
+        );
+
+        let body: serde_json::Value =
+            serde_json::from_slice(&transport.requests()[0].body).unwrap();
+        assert_eq!(body["account_type"], "depository");
+        assert_eq!(body["transactions"][0]["direction"], "OUTFLOW");
+    }
+
+    #[tokio::test]
+    async fn test_get_recurring_transactions() {
+        let (client, transport) = get_mock_client();
+        transport.push_json(
+            "transactions/recurring/get",
+            reqwest::StatusCode::OK,
+            serde_json::json!({
+                "request_id": "tbFyCEqkU774ZGG",
+                "updated_datetime": "2022-05-01T00:00:00Z",
+                "inflow_streams": [{
+                    "account_id": "lPNjeW1nR6CDn5okmGQ6hEpMo4lLNoSrzqDje",
+                    "stream_id": "no86Eox18VHMvaOVL7gPUM9ap3aR1LsAVZ5nc",
+                    "category": ["Transfer", "Payroll"],
+                    "category_id": "21009000",
+                    "description": "Platypus Payroll",
+                    "merchant_name": null,
+                    "first_date": "2022-02-28",
+                    "last_date": "2022-04-30",
+                    "frequency": "SEMI_MONTHLY",
+                    "transaction_ids": ["nkeaNrDGrhdo6c4qZWDA8ekuIPuJ4Avg5nKfw"],
+                    "average_amount": {"amount": -800, "iso_currency_code": "USD", "unofficial_currency_code": null},
+                    "last_amount": {"amount": -1000, "iso_currency_code": "USD", "unofficial_currency_code": null},
+                    "is_active": true,
+                    "status": "MATURE"
+                }],
+                "outflow_streams": [{
+                    "account_id": "lPNjeW1nR6CDn5okmGQ6hEpMo4lLNoSrzqDff",
+                    "stream_id": "no86Eox18VHMvaOVL7gPUM9ap3aR1LsAVZ5nd",
+                    "category": ["Service", "Utilities", "Electric"],
+                    "category_id": "18068005",
+                    "description": "ConEd Bill Payment",
+                    "merchant_name": "ConEd",
+                    "first_date": "2022-02-04",
+                    "last_date": "2022-05-02",
+                    "frequency": "FORTNIGHTLY",
+                    "transaction_ids": ["yhnUVvtcGGcCKU0bcz8PDQr5ZUxUXebUvbKC0"],
+                    "average_amount": {"amount": 85, "iso_currency_code": "USD", "unofficial_currency_code": null},
+                    "last_amount": null,
+                    "is_active": true,
+                    "status": "EARLY_DETECTION"
+                }]
+            }),
+        );
+
+        let resp = client
+            .get_recurring_transactions("access_token", Some(&["account_id"]))
+            .await
+            .unwrap();
+        assert_eq!(resp.inflow_streams.len(), 1);
+        let inflow = &resp.inflow_streams[0];
+        assert_eq!(inflow.frequency, TransactionStreamFrequency::SemiMonthly);
+        assert_eq!(inflow.status, Some(TransactionStreamStatus::Mature));
+        assert_eq!(inflow.average_amount.amount, Some(Money::from(-800)));
+        assert_eq!(
+            inflow.average_amount.iso_currency_code.as_deref(),
+            Some("USD")
+        );
+        assert_eq!(
+            inflow.last_amount.as_ref().unwrap().amount,
+            Some(Money::from(-1000))
+        );
+
+        assert_eq!(resp.outflow_streams.len(), 1);
+        let outflow = &resp.outflow_streams[0];
+        assert_eq!(
+            outflow.frequency,
+            TransactionStreamFrequency::Other("FORTNIGHTLY".to_string())
+        );
+        assert_eq!(
+            outflow.status,
+            Some(TransactionStreamStatus::EarlyDetection)
+        );
+        assert_eq!(outflow.average_amount.amount, Some(Money::from(85)));
+        assert!(outflow.last_amount.is_none());
+
+        let body: serde_json::Value =
+            serde_json::from_slice(&transport.requests()[0].body).unwrap();
+        assert_eq!(body["access_token"], "access_token");
+        assert_eq!(body["account_ids"], serde_json::json!(["account_id"]));
+    }
+
+    #[test]
+    fn test_personal_finance_category() {
+        let category: PersonalFinanceCategory = serde_json::from_value(serde_json::json!({
+            "primary": "FOOD_AND_DRINK",
+            "detailed": "FOOD_AND_DRINK_COFFEE",
+            "confidence_level": "VERY_HIGH"
+        }))
+        .unwrap();
+        assert_eq!(category.primary, "FOOD_AND_DRINK");
+        assert_eq!(category.confidence_level, Some(ConfidenceLevel::VeryHigh));
+    }
+
+    #[test]
+    fn test_original_description() {
+        let mut json = transaction_json("lPNjeW1nR6CDn5okmGQ6hEpMo4lLNoSrzqDje", 2307);
+        let transaction: Transaction = serde_json::from_value(json.clone()).unwrap();
+        assert!(transaction.original_description.is_none());
+
+        json["original_description"] = "APPLE STORE #R102 SAN FRANCISCO CA".into();
+        let transaction: Transaction = serde_json::from_value(json).unwrap();
+        assert_eq!(
+            transaction.original_description.as_deref(),
+            Some("APPLE STORE #R102 SAN FRANCISCO CA")
+        );
+    }
+
+    #[test]
+    fn test_counterparties() {
+        let transaction: Transaction = serde_json::from_value(transaction_json(
+            "lPNjeW1nR6CDn5okmGQ6hEpMo4lLNoSrzqDje",
+            2307,
+        ))
+        .unwrap();
+        assert!(transaction.logo_url.is_none());
+        assert!(transaction.counterparties.is_none());
+
+        let mut json = transaction_json("lPNjeW1nR6CDn5okmGQ6hEpMo4lLNoSrzqDje", 2307);
+        json["logo_url"] = "https://plaid-merchant-logos.plaid.com/apple_1.png".into();
+        json["website"] = "apple.com".into();
+        json["counterparties"] = serde_json::json!([{
+            "name": "Apple",
+            "type": "merchant",
+            "logo_url": "https://plaid-merchant-logos.plaid.com/apple_1.png",
+            "website": "apple.com",
+            "confidence_level": "VERY_HIGH"
+        }]);
+        let transaction: Transaction = serde_json::from_value(json).unwrap();
+        assert_eq!(transaction.website.as_deref(), Some("apple.com"));
+        let counterparties = transaction.counterparties.unwrap();
+        assert_eq!(counterparties[0].name, "Apple");
+        assert_eq!(
+            counterparties[0].counterparty_type,
+            CounterpartyType::Merchant
+        );
+        assert_eq!(
+            counterparties[0].logo_url.as_deref(),
+            Some("https://plaid-merchant-logos.plaid.com/apple_1.png")
+        );
+    }
+
     #[tokio::test]
     async fn test_get_transactions() {
         let client = get_test_client();
         let sandbox_resp = client
-            .create_sandbox_public_token(SANDBOX_INSTITUTION, TEST_PRODUCTS)
+            .create_sandbox_public_token(SANDBOX_INSTITUTION, TEST_PRODUCTS, None)
             .await
             .unwrap();
         let token_resp = client
@@ -243,9 +1399,9 @@ mod tests {
         while resp.is_err() {
             let err = resp.unwrap_err();
             if let Error::Plaid(err) = err {
-                assert_eq!(err.error_code, "PRODUCT_NOT_READY");
+                assert_eq!(err.code(), PlaidErrorCode::ProductNotReady);
             } else {
-                assert!(false);
+                panic!("unexpected error: {}", err);
             }
             tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
             resp = client
@@ -265,15 +1421,16 @@ mod tests {
                     account_ids: None,
                     count: 2,
                     offset: 1,
+                    ..Default::default()
                 }),
             )
             .await;
         while resp.is_err() {
             let err = resp.unwrap_err();
             if let Error::Plaid(err) = err {
-                assert_eq!(err.error_code, "PRODUCT_NOT_READY");
+                assert_eq!(err.code(), PlaidErrorCode::ProductNotReady);
             } else {
-                assert!(false);
+                panic!("unexpected error: {}", err);
             }
             tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
             resp = client
@@ -285,6 +1442,7 @@ mod tests {
                         account_ids: None,
                         count: 2,
                         offset: 1,
+                        ..Default::default()
                     }),
                 )
                 .await;
@@ -293,11 +1451,228 @@ mod tests {
         assert_ne!(resp.transactions.len(), 0);
     }
 
+    #[tokio::test]
+    async fn test_get_all_transactions() {
+        let client = get_test_client();
+        let sandbox_resp = client
+            .create_sandbox_public_token(SANDBOX_INSTITUTION, TEST_PRODUCTS, None)
+            .await
+            .unwrap();
+        let token_resp = client
+            .exchange_public_token(&sandbox_resp.public_token)
+            .await
+            .unwrap();
+        let end_date = Utc::now().naive_utc().date();
+        let start_date = end_date.sub(chrono::Duration::days(365));
+
+        let mut resp = client
+            .get_all_transactions(&token_resp.access_token, start_date, end_date, None)
+            .await;
+        while resp.is_err() {
+            let err = resp.unwrap_err();
+            if let Error::Plaid(err) = err {
+                assert_eq!(err.code(), PlaidErrorCode::ProductNotReady);
+            } else {
+                panic!("unexpected error: {}", err);
+            }
+            tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+            resp = client
+                .get_all_transactions(&token_resp.access_token, start_date, end_date, None)
+                .await;
+        }
+        let resp = resp.unwrap();
+        assert_eq!(resp.transactions.len() as i32, resp.total_transactions);
+    }
+
+    #[tokio::test]
+    async fn test_sync_transactions_cursor() {
+        let (client, transport) = get_mock_client();
+        for next_cursor in &[
+            "tVUUL15lYQN5rBnfDIc1I8xudpGdIlw9nsgeXWvhOfkECvUeR663i3Dt1uf",
+            "",
+        ] {
+            transport.push_json(
+                "transactions/sync",
+                reqwest::StatusCode::OK,
+                serde_json::json!({
+                    "request_id": "Wvhy9PZHQLV8njG",
+                    "added": [],
+                    "modified": [],
+                    "removed": [],
+                    "next_cursor": next_cursor,
+                    "has_more": false
+                }),
+            );
+        }
+
+        let resp = client
+            .sync_transactions("access-sandbox-123", None, None)
+            .await
+            .unwrap();
+        let cursor = resp.next_cursor;
+        assert_eq!(
+            serde_json::to_value(&cursor).unwrap(),
+            "tVUUL15lYQN5rBnfDIc1I8xudpGdIlw9nsgeXWvhOfkECvUeR663i3Dt1uf"
+        );
+        let resp = client
+            .sync_transactions("access-sandbox-123", Some(&cursor), None)
+            .await
+            .unwrap();
+        assert!(resp.next_cursor.is_empty());
+
+        let requests = transport.requests();
+        let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+        assert!(body.get("cursor").is_none());
+        let body: serde_json::Value = serde_json::from_slice(&requests[1].body).unwrap();
+        assert_eq!(body["cursor"], cursor.as_str());
+    }
+
+    fn sync_mutation_error() -> serde_json::Value {
+        serde_json::json!({
+            "request_id": "Wvhy9PZHQLV8njG",
+            "error_type": "TRANSACTIONS_ERROR",
+            "error_code": "TRANSACTIONS_SYNC_MUTATION_DURING_PAGINATION",
+            "error_message": "Underlying transaction data changed since last page was fetched. Please restart pagination from last update.",
+            "display_message": null
+        })
+    }
+
+    #[tokio::test]
+    async fn test_sync_all_transactions_restart() {
+        let (client, transport) = get_mock_client();
+        transport.push_json(
+            "transactions/sync",
+            reqwest::StatusCode::OK,
+            serde_json::json!({
+                "request_id": "Wvhy9PZHQLV8njG",
+                "added": [transaction_json("stale", 10)],
+                "modified": [],
+                "removed": [],
+                "next_cursor": "page-2",
+                "has_more": true
+            }),
+        );
+        transport.push_json(
+            "transactions/sync",
+            reqwest::StatusCode::BAD_REQUEST,
+            sync_mutation_error(),
+        );
+        transport.push_json(
+            "transactions/sync",
+            reqwest::StatusCode::OK,
+            serde_json::json!({
+                "request_id": "Wvhy9PZHQLV8njG",
+                "added": [transaction_json("a", 10), transaction_json("b", 20)],
+                "modified": [],
+                "removed": [],
+                "next_cursor": "final",
+                "has_more": false
+            }),
+        );
+
+        let resp = client
+            .sync_all_transactions("access-sandbox-123", None)
+            .await
+            .unwrap();
+        let ids: Vec<_> = resp
+            .added
+            .iter()
+            .map(|t| t.transaction_id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["a", "b"]);
+        assert_eq!(resp.next_cursor.as_str(), "final");
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 3);
+        let body: serde_json::Value = serde_json::from_slice(&requests[1].body).unwrap();
+        assert_eq!(body["cursor"], "page-2");
+        let body: serde_json::Value = serde_json::from_slice(&requests[2].body).unwrap();
+        assert!(body.get("cursor").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_sync_all_transactions_gives_up() {
+        let (client, transport) = get_mock_client();
+        for _ in 0..SYNC_MUTATION_RESTART_ATTEMPTS {
+            transport.push_json(
+                "transactions/sync",
+                reqwest::StatusCode::BAD_REQUEST,
+                sync_mutation_error(),
+            );
+        }
+
+        match client
+            .sync_all_transactions("access-sandbox-123", None)
+            .await
+        {
+            Err(Error::Plaid(err)) => assert_eq!(
+                err.code(),
+                PlaidErrorCode::TransactionsSyncMutationDuringPagination
+            ),
+            other => panic!("unexpected result: {:?}", other),
+        }
+        assert_eq!(
+            transport.requests().len(),
+            SYNC_MUTATION_RESTART_ATTEMPTS as usize
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sync_transactions() {
+        let client = get_test_client();
+        let sandbox_resp = client
+            .create_sandbox_public_token(SANDBOX_INSTITUTION, TEST_PRODUCTS, None)
+            .await
+            .unwrap();
+        let token_resp = client
+            .exchange_public_token(&sandbox_resp.public_token)
+            .await
+            .unwrap();
+
+        let mut resp = client
+            .sync_transactions(&token_resp.access_token, None, Some(100))
+            .await
+            .unwrap();
+        while resp.has_more {
+            resp = client
+                .sync_transactions(&token_resp.access_token, Some(&resp.next_cursor), Some(100))
+                .await
+                .unwrap();
+        }
+        assert!(!resp.next_cursor.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_sync_all_transactions() {
+        let client = get_test_client();
+        let sandbox_resp = client
+            .create_sandbox_public_token(SANDBOX_INSTITUTION, TEST_PRODUCTS, None)
+            .await
+            .unwrap();
+        let token_resp = client
+            .exchange_public_token(&sandbox_resp.public_token)
+            .await
+            .unwrap();
+
+        let resp = client
+            .sync_all_transactions(&token_resp.access_token, None)
+            .await
+            .unwrap();
+        assert!(!resp.has_more);
+        assert!(!resp.next_cursor.is_empty());
+
+        let resp = client
+            .sync_all_transactions(&token_resp.access_token, Some(&resp.next_cursor))
+            .await
+            .unwrap();
+        assert!(resp.added.is_empty());
+    }
+
     #[tokio::test]
     async fn test_refresh_transactions() {
         let client = get_test_client();
         let sandbox_resp = client
-            .create_sandbox_public_token(SANDBOX_INSTITUTION, TEST_PRODUCTS)
+            .create_sandbox_public_token(SANDBOX_INSTITUTION, TEST_PRODUCTS, None)
             .await
             .unwrap();
         let token_resp = client
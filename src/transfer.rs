@@ -0,0 +1,461 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::client::Client;
+use crate::errors::Result;
+
+string_enum! {
+    /// The type of transfer.
+    pub enum TransferType {
+        /// A transfer that pulls funds from the user's account.
+        Debit => "debit",
+        /// A transfer that sends funds to the user's account.
+        Credit => "credit",
+        _ => Other,
+    }
+}
+
+string_enum! {
+    /// The network or rails used for the transfer.
+    pub enum TransferNetwork {
+        /// Standard ACH.
+        Ach => "ach",
+        /// Same-day ACH.
+        SameDayAch => "same-day-ach",
+        /// Real-time payments.
+        Rtp => "rtp",
+        /// Wire transfer.
+        Wire => "wire",
+        _ => Other,
+    }
+}
+
+string_enum! {
+    /// The decision of a transfer authorization.
+    pub enum TransferAuthorizationDecision {
+        /// The transfer is approved and can be created.
+        Approved => "approved",
+        /// The transfer is declined and cannot be created.
+        Declined => "declined",
+        /// The user must take action in Link before the transfer can be authorized.
+        UserActionRequired => "user_action_required",
+        _ => Other,
+    }
+}
+
+string_enum! {
+    /// The status of a transfer.
+    pub enum TransferStatus {
+        /// A new transfer was created; it is in the pending state.
+        Pending => "pending",
+        /// The transfer has been successfully submitted to the payment network.
+        Posted => "posted",
+        /// The transfer has been settled.
+        Settled => "settled",
+        /// The transfer was cancelled by the client.
+        Cancelled => "cancelled",
+        /// The transfer failed, no funds were moved.
+        Failed => "failed",
+        /// A posted transfer was returned.
+        Returned => "returned",
+        _ => Other,
+    }
+}
+
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct TransferUserAddress<'a> {
+    /// The street number and name (i.e., "100 Market St.").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub street: Option<&'a str>,
+    /// Ex. "San Francisco"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<&'a str>,
+    /// The state or province (e.g., "CA").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<&'a str>,
+    /// The postal code (e.g., "94103").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub postal_code: Option<&'a str>,
+    /// A two-letter country code (e.g., "US").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<&'a str>,
+}
+
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct TransferUser<'a> {
+    /// The user's legal name.
+    pub legal_name: &'a str,
+    /// The user's phone number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<&'a str>,
+    /// The user's email address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_address: Option<&'a str>,
+    /// The address associated with the account holder.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<TransferUserAddress<'a>>,
+}
+
+#[derive(Serialize)]
+struct CreateTransferAuthorizationRequest<'a> {
+    client_id: &'a str,
+    secret: &'a str,
+    access_token: &'a str,
+    account_id: &'a str,
+    #[serde(rename = "type")]
+    transfer_type: TransferType,
+    network: TransferNetwork,
+    amount: &'a str,
+    user: TransferUser<'a>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TransferAuthorizationDecisionRationale {
+    /// A code representing the rationale for approving or declining the proposed transfer.
+    pub code: String,
+    /// A human-readable description of the code associated with a transfer approval or transfer decline.
+    pub description: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TransferAuthorization {
+    /// Plaid's unique identifier for a transfer authorization.
+    pub id: String,
+    /// The datetime representing when the authorization was created, in the format "2006-01-02T15:04:05Z".
+    pub created: DateTime<Utc>,
+    /// A decision regarding the proposed transfer.
+    pub decision: TransferAuthorizationDecision,
+    /// The rationale for Plaid's decision regarding a proposed transfer. Will be null for approved transfers.
+    pub decision_rationale: Option<TransferAuthorizationDecisionRationale>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CreateTransferAuthorizationResponse {
+    /// A unique identifier for the request, which can be used for troubleshooting. This identifier, like all Plaid identifiers, is case sensitive.
+    pub request_id: String,
+    /// Contains the authorization decision for a proposed transfer.
+    pub authorization: TransferAuthorization,
+}
+
+#[derive(Serialize)]
+struct CreateTransferRequest<'a> {
+    client_id: &'a str,
+    secret: &'a str,
+    access_token: &'a str,
+    account_id: &'a str,
+    authorization_id: &'a str,
+    description: &'a str,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Transfer {
+    /// Plaid's unique identifier for a transfer.
+    pub id: String,
+    /// Plaid's unique identifier for a transfer authorization.
+    pub authorization_id: Option<String>,
+    /// The Plaid account_id corresponding to the end-user account that will be debited or credited.
+    pub account_id: Option<String>,
+    /// The type of transfer.
+    #[serde(rename = "type")]
+    pub transfer_type: TransferType,
+    /// The network or rails used for the transfer.
+    pub network: TransferNetwork,
+    /// The amount of the transfer (decimal string with two digits of precision e.g. "10.00").
+    pub amount: String,
+    /// The currency of the transfer amount, e.g. "USD"
+    pub iso_currency_code: Option<String>,
+    /// The description of the transfer.
+    pub description: String,
+    /// The status of the transfer.
+    pub status: TransferStatus,
+    /// The datetime when this transfer was created. This will be of the form 2006-01-02T15:04:05Z
+    pub created: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CreateTransferResponse {
+    /// A unique identifier for the request, which can be used for troubleshooting. This identifier, like all Plaid identifiers, is case sensitive.
+    pub request_id: String,
+    /// Represents a transfer within the Transfers API.
+    pub transfer: Transfer,
+}
+
+#[derive(Serialize)]
+struct GetTransferRequest<'a> {
+    client_id: &'a str,
+    secret: &'a str,
+    transfer_id: &'a str,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GetTransferResponse {
+    /// A unique identifier for the request, which can be used for troubleshooting. This identifier, like all Plaid identifiers, is case sensitive.
+    pub request_id: String,
+    /// Represents a transfer within the Transfers API.
+    pub transfer: Transfer,
+}
+
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct ListTransfersOptions {
+    /// The start datetime of transfers to list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<DateTime<Utc>>,
+    /// The end datetime of transfers to list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<DateTime<Utc>>,
+    /// The maximum number of transfers to return.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<i32>,
+    /// The number of transfers to skip before returning results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<i32>,
+}
+
+#[derive(Serialize)]
+struct ListTransfersRequest<'a> {
+    client_id: &'a str,
+    secret: &'a str,
+    #[serde(flatten)]
+    options: ListTransfersOptions,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ListTransfersResponse {
+    /// A unique identifier for the request, which can be used for troubleshooting. This identifier, like all Plaid identifiers, is case sensitive.
+    pub request_id: String,
+    /// The transfers matching the request, ordered by creation time descending.
+    pub transfers: Vec<Transfer>,
+}
+
+impl Client {
+    /// Create a transfer authorization.
+    ///
+    /// Use the /transfer/authorization/create endpoint to determine transfer failure risk. Plaid will return a decision along with an authorization_id, which must be passed to /transfer/create.
+    ///
+    /// * `access_token` - The Plaid access_token for the account that will be debited or credited.
+    /// * `account_id` - The Plaid account_id for the account that will be debited or credited.
+    /// * `transfer_type` - The type of transfer.
+    /// * `network` - The network or rails used for the transfer.
+    /// * `amount` - The amount of the transfer (decimal string with two digits of precision e.g. "10.00").
+    /// * `user` - The legal name and other information for the account holder.
+    pub async fn create_transfer_authorization<'a>(
+        &self,
+        access_token: &str,
+        account_id: &str,
+        transfer_type: TransferType,
+        network: TransferNetwork,
+        amount: &str,
+        user: TransferUser<'a>,
+    ) -> Result<CreateTransferAuthorizationResponse> {
+        self.send_request(
+            "transfer/authorization/create",
+            &CreateTransferAuthorizationRequest {
+                client_id: self.client_id(),
+                secret: self.secret(),
+                access_token,
+                account_id,
+                transfer_type,
+                network,
+                amount,
+                user,
+            },
+        )
+        .await
+    }
+
+    /// Create a transfer.
+    ///
+    /// Use the /transfer/create endpoint to initiate a new transfer.
+    ///
+    /// * `access_token` - The Plaid access_token for the account that will be debited or credited.
+    /// * `account_id` - The Plaid account_id for the account that will be debited or credited.
+    /// * `authorization_id` - Plaid's unique identifier for a transfer authorization, returned by /transfer/authorization/create.
+    /// * `description` - The transfer description. Maximum of 15 characters.
+    /// * `idempotency_key` - An optional key sent in the Plaid-Idempotency-Key header, so that retrying the request does not create a second transfer.
+    ///
+    /// Generate the idempotency key yourself, unique to the transfer, and store it before the first attempt so that retries reuse it:
+    ///
+    /// ```ignore
+    /// // `payment` is your own record of the transfer, created with a fresh UUID as its idempotency_key.
+    /// let resp = client
+    ///     .create_transfer(
+    ///         &access_token,
+    ///         &account_id,
+    ///         &authorization.id,
+    ///         "Payment",
+    ///         Some(&payment.idempotency_key),
+    ///     )
+    ///     .await?;
+    /// ```
+    pub async fn create_transfer(
+        &self,
+        access_token: &str,
+        account_id: &str,
+        authorization_id: &str,
+        description: &str,
+        idempotency_key: Option<&str>,
+    ) -> Result<CreateTransferResponse> {
+        self.send_request_with_key(
+            "transfer/create",
+            &CreateTransferRequest {
+                client_id: self.client_id(),
+                secret: self.secret(),
+                access_token,
+                account_id,
+                authorization_id,
+                description,
+            },
+            idempotency_key,
+        )
+        .await
+        .map(|(resp, _)| resp)
+    }
+
+    /// Retrieve a transfer.
+    ///
+    /// The /transfer/get endpoint fetches information about the transfer corresponding to the given transfer_id.
+    ///
+    /// * `transfer_id` - Plaid's unique identifier for a transfer.
+    pub async fn get_transfer(&self, transfer_id: &str) -> Result<GetTransferResponse> {
+        self.send_request(
+            "transfer/get",
+            &GetTransferRequest {
+                client_id: self.client_id(),
+                secret: self.secret(),
+                transfer_id,
+            },
+        )
+        .await
+    }
+
+    /// List transfers.
+    ///
+    /// Use the /transfer/list endpoint to see a list of all your transfers and their statuses. Results are paginated; use the count and offset query parameters to retrieve the desired transfers.
+    ///
+    /// * `options` - Optional date range and pagination parameters.
+    pub async fn list_transfers(
+        &self,
+        options: Option<ListTransfersOptions>,
+    ) -> Result<ListTransfersResponse> {
+        self.send_request(
+            "transfer/list",
+            &ListTransfersRequest {
+                client_id: self.client_id(),
+                secret: self.secret(),
+                options: options.unwrap_or_default(),
+            },
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::tests::{get_mock_client, get_test_client, SANDBOX_INSTITUTION};
+    use crate::types::Product;
+
+    #[tokio::test]
+    async fn test_create_transfer_idempotency_key() {
+        let (client, transport) = get_mock_client();
+        transport.push_json(
+            "transfer/create",
+            reqwest::StatusCode::OK,
+            serde_json::json!({
+                "request_id": "saKrIBuEB9qJZno",
+                "transfer": {
+                    "id": "460cbe92-2dcc-8eae-5ad6-b37d0ec90fd9",
+                    "authorization_id": "231h012308h3101z21909ae3",
+                    "account_id": "3gE5gnRzNyfXpBK5wEEKcymJ5albGVUqg77gr",
+                    "type": "credit",
+                    "network": "ach",
+                    "amount": "12.34",
+                    "iso_currency_code": "USD",
+                    "description": "Payment",
+                    "status": "pending",
+                    "created": "2020-08-06T17:27:15Z"
+                }
+            }),
+        );
+        let resp = client
+            .create_transfer(
+                "access-sandbox-71e02f71-0960-4a27-abd2-5631e04f2175",
+                "3gE5gnRzNyfXpBK5wEEKcymJ5albGVUqg77gr",
+                "231h012308h3101z21909ae3",
+                "Payment",
+                Some("8b6f3e12-5d4a-4f0e-9a71-3c2b1d0e9f88"),
+            )
+            .await
+            .unwrap();
+        assert_eq!(resp.transfer.status, TransferStatus::Pending);
+        assert_eq!(
+            transport.requests()[0].headers["Plaid-Idempotency-Key"],
+            "8b6f3e12-5d4a-4f0e-9a71-3c2b1d0e9f88"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_transfer_then_get() {
+        let client = get_test_client();
+        let sandbox_resp = client
+            .create_sandbox_public_token(SANDBOX_INSTITUTION, &[Product::Transfer], None)
+            .await
+            .unwrap();
+        let token_resp = client
+            .exchange_public_token(&sandbox_resp.public_token)
+            .await
+            .unwrap();
+        let accounts_resp = client
+            .get_accounts(&token_resp.access_token, None)
+            .await
+            .unwrap();
+        let account_id = &accounts_resp.accounts[0].account_id;
+
+        let auth_resp = client
+            .create_transfer_authorization(
+                &token_resp.access_token,
+                account_id,
+                TransferType::Debit,
+                TransferNetwork::Ach,
+                "1.00",
+                TransferUser {
+                    legal_name: "Anne Charleston",
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            auth_resp.authorization.decision,
+            TransferAuthorizationDecision::Approved
+        );
+
+        let create_resp = client
+            .create_transfer(
+                &token_resp.access_token,
+                account_id,
+                &auth_resp.authorization.id,
+                "Payment",
+                Some(&format!("test-transfer-{}", Utc::now().timestamp_millis())),
+            )
+            .await
+            .unwrap();
+        assert_eq!(create_resp.transfer.amount, "1.00");
+
+        let get_resp = client.get_transfer(&create_resp.transfer.id).await.unwrap();
+        assert_eq!(get_resp.transfer.id, create_resp.transfer.id);
+        assert_eq!(get_resp.transfer.transfer_type, TransferType::Debit);
+
+        let list_resp = client
+            .list_transfers(Some(ListTransfersOptions {
+                count: Some(25),
+                ..Default::default()
+            }))
+            .await
+            .unwrap();
+        assert!(list_resp
+            .transfers
+            .iter()
+            .any(|t| t.id == create_resp.transfer.id));
+    }
+}
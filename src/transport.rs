@@ -0,0 +1,236 @@
+use async_trait::async_trait;
+use reqwest::header::HeaderMap;
+use reqwest::{StatusCode, Url};
+use std::fmt;
+
+#[cfg(any(test, feature = "mock"))]
+use std::collections::{HashMap, VecDeque};
+#[cfg(any(test, feature = "mock"))]
+use std::sync::Mutex;
+
+#[cfg(any(test, feature = "mock"))]
+use crate::errors::Error;
+use crate::errors::Result;
+
+/// An HTTP request to the Plaid API, as handed to a [`Transport`].
+///
+/// The `Debug` output prints the size of the body rather than its contents, since the body carries the client_id and secret.
+#[derive(Clone)]
+pub struct TransportRequest {
+    /// The full URL of the endpoint.
+    pub url: Url,
+    /// The headers to send, including `Content-Type` and any `Plaid-Version` or `Plaid-Idempotency-Key`.
+    pub headers: HeaderMap,
+    /// The JSON encoded request body, including the client_id and secret.
+    pub body: Vec<u8>,
+}
+
+impl fmt::Debug for TransportRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TransportRequest")
+            .field("url", &self.url)
+            .field("headers", &self.headers)
+            .field("body", &format_args!("<{} bytes>", self.body.len()))
+            .finish()
+    }
+}
+
+/// An HTTP response from the Plaid API, as returned by a [`Transport`].
+#[derive(Debug, Clone)]
+pub struct TransportResponse {
+    /// The HTTP status code of the response.
+    pub status: StatusCode,
+    /// The HTTP headers of the response.
+    pub headers: HeaderMap,
+    /// The raw response body.
+    pub body: Vec<u8>,
+}
+
+/// Sends requests built by a [`Client`](crate::Client) over the network.
+///
+/// The default implementation is [`ReqwestTransport`]. Implement this trait to intercept requests, for example to return canned responses in tests.
+#[async_trait]
+pub trait Transport: fmt::Debug + Send + Sync {
+    /// Send a request and return the response, whatever its status code.
+    async fn send(&self, request: TransportRequest) -> Result<TransportResponse>;
+}
+
+/// A [`Transport`] that sends requests with a `reqwest::Client`.
+#[derive(Debug, Clone, Default)]
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+}
+
+impl ReqwestTransport {
+    /// Create a transport using the supplied HTTP client.
+    pub fn new(client: reqwest::Client) -> ReqwestTransport {
+        ReqwestTransport { client }
+    }
+}
+
+#[async_trait]
+impl Transport for ReqwestTransport {
+    async fn send(&self, request: TransportRequest) -> Result<TransportResponse> {
+        let resp = self
+            .client
+            .post(request.url)
+            .headers(request.headers)
+            .body(request.body)
+            .send()
+            .await?;
+        Ok(TransportResponse {
+            status: resp.status(),
+            headers: resp.headers().clone(),
+            body: resp.bytes().await?.to_vec(),
+        })
+    }
+}
+
+/// A [`Transport`] that returns preloaded responses instead of sending requests, for use in tests.
+///
+/// Responses are queued per endpoint path, e.g. `accounts/get`, and returned in the order they were added. Requests that were sent are recorded and can be inspected with [`MockTransport::requests`].
+///
+/// Available with the `mock` feature.
+#[cfg(any(test, feature = "mock"))]
+#[derive(Debug, Default)]
+pub struct MockTransport {
+    responses: Mutex<HashMap<String, VecDeque<TransportResponse>>>,
+    requests: Mutex<Vec<TransportRequest>>,
+}
+
+#[cfg(any(test, feature = "mock"))]
+impl MockTransport {
+    /// Create a mock transport with no queued responses.
+    pub fn new() -> MockTransport {
+        Default::default()
+    }
+
+    /// Queue a JSON response for an endpoint.
+    ///
+    /// * `path` - The endpoint path, e.g. `accounts/get`.
+    /// * `status` - The HTTP status code to respond with.
+    /// * `body` - The JSON body to respond with.
+    pub fn push_json(&self, path: &str, status: StatusCode, body: serde_json::Value) {
+        self.push_response(
+            path,
+            TransportResponse {
+                status,
+                headers: HeaderMap::new(),
+                body: body.to_string().into_bytes(),
+            },
+        );
+    }
+
+    /// Queue a raw response for an endpoint.
+    ///
+    /// * `path` - The endpoint path, e.g. `accounts/get`.
+    /// * `response` - The response to return.
+    pub fn push_response(&self, path: &str, response: TransportResponse) {
+        self.responses
+            .lock()
+            .unwrap()
+            .entry(path.trim_start_matches('/').to_string())
+            .or_default()
+            .push_back(response);
+    }
+
+    /// Returns the requests sent through this transport so far.
+    pub fn requests(&self) -> Vec<TransportRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+#[cfg(any(test, feature = "mock"))]
+#[async_trait]
+impl Transport for MockTransport {
+    async fn send(&self, request: TransportRequest) -> Result<TransportResponse> {
+        let path = request.url.path().trim_start_matches('/').to_string();
+        self.requests.lock().unwrap().push(request);
+        self.responses
+            .lock()
+            .unwrap()
+            .get_mut(&path)
+            .and_then(|responses| responses.pop_front())
+            .ok_or_else(|| Error::InvalidRequest(format!("no mock response queued for {}", path)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::tests::get_mock_client;
+    use crate::errors::PlaidErrorCode;
+
+    #[tokio::test]
+    async fn test_mock_transport() {
+        let (client, transport) = get_mock_client();
+        transport.push_json(
+            "item/remove",
+            StatusCode::OK,
+            serde_json::json!({"request_id": "m8MDnv9okwxFNBV"}),
+        );
+        transport.push_json(
+            "item/remove",
+            StatusCode::BAD_REQUEST,
+            serde_json::json!({
+                "request_id": "HNTDNrA8F1shFEW",
+                "error_type": "INVALID_INPUT",
+                "error_code": "INVALID_ACCESS_TOKEN",
+                "error_message": "provided access token is in an invalid format",
+                "display_message": null
+            }),
+        );
+
+        let resp = client.remove_item("access-sandbox-123").await.unwrap();
+        assert_eq!(resp.request_id, "m8MDnv9okwxFNBV");
+
+        match client.remove_item("access-sandbox-123").await {
+            Err(Error::Plaid(err)) => {
+                assert_eq!(err.code(), PlaidErrorCode::InvalidAccessToken);
+                assert_eq!(err.status_code, StatusCode::BAD_REQUEST);
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+
+        assert!(matches!(
+            client.remove_item("access-sandbox-123").await,
+            Err(Error::InvalidRequest(_))
+        ));
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(
+            requests[0].url.as_str(),
+            "https://sandbox.plaid.com/item/remove"
+        );
+        let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "client_id": "client_id",
+                "secret": "secret",
+                "access_token": "access-sandbox-123"
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unexpected_response() {
+        let (client, transport) = get_mock_client();
+        transport.push_response(
+            "item/remove",
+            TransportResponse {
+                status: StatusCode::BAD_GATEWAY,
+                headers: HeaderMap::new(),
+                body: b"<html>502 Bad Gateway</html>".to_vec(),
+            },
+        );
+        match client.remove_item("access-sandbox-123").await {
+            Err(Error::UnexpectedResponse { status, body }) => {
+                assert_eq!(status, StatusCode::BAD_GATEWAY);
+                assert_eq!(body, "<html>502 Bad Gateway</html>");
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+}
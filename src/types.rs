@@ -0,0 +1,268 @@
+use serde::{Deserialize, Serialize};
+
+use crate::errors::{Error, Result};
+
+/// A monetary value sent to or returned by the Plaid API.
+///
+/// This is `f64` by default. Enable the `decimal` feature to use `rust_decimal::Decimal` instead, which (de)serializes amounts exactly without going through a binary float.
+#[cfg(not(feature = "decimal"))]
+pub type Money = f64;
+
+/// A monetary value sent to or returned by the Plaid API.
+///
+/// The `decimal` feature is enabled, so amounts are (de)serialized exactly as `rust_decimal::Decimal`. Amounts sent to Plaid are written as JSON numbers; amounts in response structs serialize as strings.
+#[cfg(feature = "decimal")]
+pub type Money = rust_decimal::Decimal;
+
+string_enum! {
+    /// A Plaid product.
+    pub enum Product {
+        /// Assets.
+        Assets => "assets",
+        /// Auth.
+        Auth => "auth",
+        /// Balance.
+        Balance => "balance",
+        /// Identity.
+        Identity => "identity",
+        /// Investments.
+        Investments => "investments",
+        /// Liabilities.
+        Liabilities => "liabilities",
+        /// Payment Initiation (UK and Europe).
+        PaymentInitiation => "payment_initiation",
+        /// Transactions.
+        Transactions => "transactions",
+        /// Credit details.
+        CreditDetails => "credit_details",
+        /// Income.
+        Income => "income",
+        /// Income verification.
+        IncomeVerification => "income_verification",
+        /// Deposit Switch.
+        DepositSwitch => "deposit_switch",
+        /// Employment.
+        Employment => "employment",
+        /// Recurring transactions.
+        RecurringTransactions => "recurring_transactions",
+        /// Signal.
+        Signal => "signal",
+        /// Transfer.
+        Transfer => "transfer",
+        _ => Other,
+    }
+}
+
+string_enum! {
+    /// An ISO-3166-1 alpha-2 country code supported by Plaid.
+    pub enum CountryCode {
+        /// United States.
+        US => "US",
+        /// United Kingdom.
+        GB => "GB",
+        /// Spain.
+        ES => "ES",
+        /// Netherlands.
+        NL => "NL",
+        /// France.
+        FR => "FR",
+        /// Ireland.
+        IE => "IE",
+        /// Canada.
+        CA => "CA",
+        /// Germany.
+        DE => "DE",
+        /// Italy.
+        IT => "IT",
+        /// Poland.
+        PL => "PL",
+        /// Denmark.
+        DK => "DK",
+        /// Norway.
+        NO => "NO",
+        /// Sweden.
+        SE => "SE",
+        /// Estonia.
+        EE => "EE",
+        /// Lithuania.
+        LT => "LT",
+        /// Latvia.
+        LV => "LV",
+        /// Portugal.
+        PT => "PT",
+        /// Belgium.
+        BE => "BE",
+        _ => Other,
+    }
+}
+
+/// A value denominated in either an ISO-4217 currency or an unofficial currency, exactly one of which is set.
+pub trait HasCurrency {
+    /// The ISO-4217 currency code. Always `None` if `unofficial_currency_code` is set.
+    fn iso_currency_code(&self) -> Option<&str>;
+
+    /// The unofficial currency code, such as a cryptocurrency. Always `None` if `iso_currency_code` is set.
+    fn unofficial_currency_code(&self) -> Option<&str>;
+
+    /// Returns whichever of the ISO-4217 or unofficial currency code is set.
+    fn currency(&self) -> Option<&str> {
+        self.iso_currency_code()
+            .or_else(|| self.unofficial_currency_code())
+    }
+
+    /// Returns true if the value is denominated in an unofficial currency.
+    fn is_unofficial(&self) -> bool {
+        self.iso_currency_code().is_none() && self.unofficial_currency_code().is_some()
+    }
+}
+
+macro_rules! impl_has_currency {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl HasCurrency for $ty {
+                fn iso_currency_code(&self) -> Option<&str> {
+                    self.iso_currency_code.as_deref()
+                }
+
+                fn unofficial_currency_code(&self) -> Option<&str> {
+                    self.unofficial_currency_code.as_deref()
+                }
+            }
+        )*
+    };
+}
+
+impl_has_currency!(
+    crate::accounts::AccountBalances,
+    crate::asset_report::AssetReportTransaction,
+    crate::asset_report::HistoricalBalance,
+    crate::holdings::Holding,
+    crate::holdings::Security,
+    crate::investment_transactions::InvestmentTransaction,
+    crate::transactions::Transaction,
+    crate::transactions::TransactionStreamAmount,
+);
+
+/// An amount of money in a given currency, as sent to and returned by the money movement endpoints.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Amount {
+    /// The ISO-4217 currency code of the amount, e.g. "GBP".
+    pub currency: String,
+    /// The value of the amount.
+    #[cfg_attr(
+        feature = "decimal",
+        serde(with = "rust_decimal::serde::arbitrary_precision")
+    )]
+    pub value: Money,
+}
+
+impl Amount {
+    /// Create an amount, checking that the currency is an ISO-4217 code.
+    ///
+    /// Returns `Error::InvalidRequest` if `currency` is not made of three uppercase ASCII letters.
+    ///
+    /// * `currency` - The ISO-4217 currency code of the amount, e.g. "GBP".
+    /// * `value` - The value of the amount.
+    pub fn new(currency: &str, value: Money) -> Result<Amount> {
+        let amount = Amount {
+            currency: currency.to_string(),
+            value,
+        };
+        amount.validate()?;
+        Ok(amount)
+    }
+
+    /// Checks that the currency is an ISO-4217 code, i.e. three uppercase ASCII letters.
+    pub fn validate(&self) -> Result<()> {
+        if self.currency.len() != 3 || !self.currency.bytes().all(|b| b.is_ascii_uppercase()) {
+            return Err(Error::InvalidRequest(format!(
+                "currency must be an ISO-4217 code, got {:?}",
+                self.currency
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_product() {
+        assert_eq!(
+            serde_json::to_string(&[Product::Auth, Product::PaymentInitiation]).unwrap(),
+            r#"["auth","payment_initiation"]"#
+        );
+        assert_eq!(Product::from("transactions"), Product::Transactions);
+        assert_eq!(Product::from("beacon").as_str(), "beacon");
+    }
+
+    #[test]
+    fn test_amount() {
+        let amount = Amount::new("GBP", Money::from(12)).unwrap();
+        let value = serde_json::to_value(&amount).unwrap();
+        assert_eq!(value["currency"], "GBP");
+        assert!(value["value"].is_number());
+        assert_eq!(serde_json::from_value::<Amount>(value).unwrap(), amount);
+        for currency in &["gbp", "GB", "POUND", ""] {
+            match Amount::new(currency, Money::from(12)) {
+                Err(Error::InvalidRequest(_)) => {}
+                other => panic!("unexpected result for {}: {:?}", currency, other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_country_code() {
+        assert_eq!(
+            serde_json::to_string(&[CountryCode::US, CountryCode::GB]).unwrap(),
+            r#"["US","GB"]"#
+        );
+        assert_eq!(CountryCode::from("CA"), CountryCode::CA);
+    }
+
+    #[test]
+    fn test_has_currency() {
+        let balances: crate::accounts::AccountBalances =
+            serde_json::from_value(serde_json::json!({
+                "available": null,
+                "current": 0.5,
+                "limit": null,
+                "iso_currency_code": null,
+                "unofficial_currency_code": "BTC"
+            }))
+            .unwrap();
+        assert_eq!(balances.currency(), Some("BTC"));
+        assert!(balances.is_unofficial());
+
+        let balances: crate::accounts::AccountBalances =
+            serde_json::from_value(serde_json::json!({
+                "available": 100,
+                "current": 110,
+                "limit": null,
+                "iso_currency_code": "USD",
+                "unofficial_currency_code": null
+            }))
+            .unwrap();
+        assert_eq!(balances.currency(), Some("USD"));
+        assert!(!balances.is_unofficial());
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn test_money_is_exact() {
+        let amounts: Vec<Money> = serde_json::from_str("[0.1, 0.2, 1234.56]").unwrap();
+        assert_eq!(amounts[0] + amounts[1], "0.3".parse::<Money>().unwrap());
+        assert_eq!(amounts[2].to_string(), "1234.56");
+        assert_eq!(
+            serde_json::to_string(&amounts).unwrap(),
+            r#"["0.1","0.2","1234.56"]"#
+        );
+        let amount = Amount::new("USD", "1234.560".parse().unwrap()).unwrap();
+        assert_eq!(
+            serde_json::to_string(&amount).unwrap(),
+            r#"{"currency":"USD","value":1234.560}"#
+        );
+    }
+}
@@ -0,0 +1,73 @@
+use serde::{Deserialize, Serialize};
+
+use crate::client::Client;
+use crate::errors::Result;
+
+#[derive(Serialize)]
+struct CreateUserRequest<'a> {
+    client_id: &'a str,
+    secret: &'a str,
+    client_user_id: &'a str,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CreateUserResponse {
+    /// A unique identifier for the request, which can be used for troubleshooting. This identifier, like all Plaid identifiers, is case sensitive.
+    pub request_id: String,
+    /// The user token associated with the User data is being requested for.
+    pub user_token: String,
+    /// The Plaid user_id of the User associated with this webhook, warning, or error.
+    pub user_id: String,
+}
+
+impl Client {
+    /// Create user.
+    ///
+    /// This endpoint should be called for each of your end users before they begin a Plaid income flow. This provides you a single token to access all income data associated with the user. You should only create one per end user. Pass the returned user_token as `LinkTokenConfigs::user_token` when creating a link token.
+    ///
+    /// * `client_user_id` - A unique ID representing the end user. Maximum of 128 characters. Typically this will be a user ID number from your application. Personally identifiable information, such as an email address or phone number, should not be used in the client_user_id.
+    pub async fn create_user(&self, client_user_id: &str) -> Result<CreateUserResponse> {
+        self.send_request(
+            "user/create",
+            &CreateUserRequest {
+                client_id: self.client_id(),
+                secret: self.secret(),
+                client_user_id,
+            },
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::tests::get_mock_client;
+    use reqwest::StatusCode;
+
+    #[tokio::test]
+    async fn test_create_user() {
+        let (client, transport) = get_mock_client();
+        transport.push_json(
+            "user/create",
+            StatusCode::OK,
+            serde_json::json!({
+                "user_token": "user-environment-1234567-abcd-abcd-1234-1234567890ab",
+                "user_id": "wz666MBjYWTp2PDzzggYhM6oWWmBb",
+                "request_id": "Aim3b"
+            }),
+        );
+        let resp = client
+            .create_user("c0e2c4ee-b763-4af5-cfe9-46a46bce883d")
+            .await
+            .unwrap();
+        assert_eq!(resp.user_id, "wz666MBjYWTp2PDzzggYhM6oWWmBb");
+        assert!(resp.user_token.starts_with("user-"));
+
+        let body: serde_json::Value =
+            serde_json::from_slice(&transport.requests()[0].body).unwrap();
+        assert_eq!(
+            body["client_user_id"],
+            "c0e2c4ee-b763-4af5-cfe9-46a46bce883d"
+        );
+    }
+}
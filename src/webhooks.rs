@@ -1,10 +1,25 @@
+use chrono::{DateTime, Utc};
+use jsonwebtoken::{Algorithm, DecodingKey, Validation};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
 
 use crate::client::Client;
-use crate::errors::Result;
+use crate::errors::{Error, ItemError, Result};
+
+/// The maximum age of a webhook, in seconds, before it is rejected by `verify_webhook`.
+const WEBHOOK_MAX_AGE_SECS: i64 = 5 * 60;
+
+/// How far in the future, in seconds, a webhook may have been issued before it is rejected by `verify_webhook`, to allow for clock skew.
+const WEBHOOK_MAX_CLOCK_SKEW_SECS: i64 = 30;
+
+/// How long `verify_webhook` caches a verification key before fetching it again, so that keys Plaid has rotated out are noticed.
+const WEBHOOK_KEY_CACHE_TTL: Duration = Duration::from_secs(60 * 60);
 
 /// A JSON Web Key (JWK) that can be used in conjunction with JWT libraries to verify Plaid webhooks
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct WebhookVerificationKey {
     /// The alg member identifies the cryptographic algorithm family used with the key.
     pub alg: String,
@@ -31,7 +46,7 @@ struct GetWebhookVerificationKeyRequest<'a> {
     key_id: &'a str,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct GetWebhookVerificationKeyResponse {
     /// A unique identifier for the request, which can be used for troubleshooting. This identifier, like all Plaid identifiers, is case sensitive.
     pub request_id: String,
@@ -39,6 +54,186 @@ pub struct GetWebhookVerificationKeyResponse {
     pub key: WebhookVerificationKey,
 }
 
+string_enum! {
+    /// The webhook_code of a webhook sent by Plaid.
+    pub enum WebhookCode {
+        /// Fired when an Item's initial transaction pull is completed.
+        InitialUpdate => "INITIAL_UPDATE",
+        /// Fired when an Item's historical transaction pull is completed.
+        HistoricalUpdate => "HISTORICAL_UPDATE",
+        /// Fired when new data is available for an Item.
+        DefaultUpdate => "DEFAULT_UPDATE",
+        /// Fired when transactions have been removed from an Item.
+        TransactionsRemoved => "TRANSACTIONS_REMOVED",
+        /// Fired when new updates are available for /transactions/sync.
+        SyncUpdatesAvailable => "SYNC_UPDATES_AVAILABLE",
+        /// Fired when recurring transactions data is updated.
+        RecurringTransactionsUpdate => "RECURRING_TRANSACTIONS_UPDATE",
+        /// Fired when an error is encountered with an Item.
+        Error => "ERROR",
+        /// Fired when an Item's access consent is expiring in 7 days.
+        PendingExpiration => "PENDING_EXPIRATION",
+        /// Fired when the end user has revoked the permission that they previously granted to access an Item.
+        UserPermissionRevoked => "USER_PERMISSION_REVOKED",
+        /// Fired when an Item's webhook is updated.
+        WebhookUpdateAcknowledged => "WEBHOOK_UPDATE_ACKNOWLEDGED",
+        /// Fired when Plaid detects a new account for Items created or updated with Account Select v2.
+        NewAccountsAvailable => "NEW_ACCOUNTS_AVAILABLE",
+        /// Fired when an Item that was in an error state has been repaired.
+        LoginRepaired => "LOGIN_REPAIRED",
+        /// Fired when an Item is verified via automated micro-deposits.
+        AutomaticallyVerified => "AUTOMATICALLY_VERIFIED",
+        /// Fired when an Item was not verified via automated micro-deposits after seven days.
+        VerificationExpired => "VERIFICATION_EXPIRED",
+        /// Fired when an Asset Report has finished generating.
+        ProductReady => "PRODUCT_READY",
+        _ => Other,
+    }
+}
+
+/// The JSON body of a webhook sent by Plaid, keyed on its webhook_type.
+///
+/// Webhook types not modelled by this crate deserialize to `Other`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "webhook_type")]
+pub enum WebhookEvent {
+    /// A TRANSACTIONS webhook.
+    #[serde(rename = "TRANSACTIONS")]
+    Transactions {
+        /// The webhook_code of the webhook.
+        #[serde(rename = "webhook_code")]
+        code: WebhookCode,
+        /// The item_id of the Item associated with this webhook.
+        item_id: String,
+        /// The number of new transactions available.
+        new_transactions: Option<i32>,
+        /// An array of transaction_ids corresponding to the removed transactions.
+        removed_transactions: Option<Vec<String>>,
+        /// The error that occurred while updating transactions, if any.
+        error: Option<ItemError>,
+    },
+    /// An ITEM webhook.
+    #[serde(rename = "ITEM")]
+    Item {
+        /// The webhook_code of the webhook.
+        #[serde(rename = "webhook_code")]
+        code: WebhookCode,
+        /// The item_id of the Item associated with this webhook.
+        item_id: String,
+        /// The error that the Item has entered, for ERROR webhooks.
+        error: Option<ItemError>,
+        /// The new webhook URL, for WEBHOOK_UPDATE_ACKNOWLEDGED webhooks.
+        new_webhook_url: Option<String>,
+        /// The date and time at which the Item's access consent will expire, for PENDING_EXPIRATION webhooks.
+        consent_expiration_time: Option<DateTime<Utc>>,
+    },
+    /// An AUTH webhook.
+    #[serde(rename = "AUTH")]
+    Auth {
+        /// The webhook_code of the webhook.
+        #[serde(rename = "webhook_code")]
+        code: WebhookCode,
+        /// The item_id of the Item associated with this webhook.
+        item_id: String,
+        /// The account_id of the account associated with the webhook.
+        account_id: Option<String>,
+        /// The error that occurred, if any.
+        error: Option<ItemError>,
+    },
+    /// A HOLDINGS webhook.
+    #[serde(rename = "HOLDINGS")]
+    Holdings {
+        /// The webhook_code of the webhook.
+        #[serde(rename = "webhook_code")]
+        code: WebhookCode,
+        /// The item_id of the Item associated with this webhook.
+        item_id: String,
+        /// The number of new holdings reported since the last time this webhook was fired.
+        new_holdings: Option<i32>,
+        /// The number of updated holdings reported since the last time this webhook was fired.
+        updated_holdings: Option<i32>,
+        /// The error that occurred while updating holdings, if any.
+        error: Option<ItemError>,
+    },
+    /// An INVESTMENTS_TRANSACTIONS webhook.
+    #[serde(rename = "INVESTMENTS_TRANSACTIONS")]
+    InvestmentsTransactions {
+        /// The webhook_code of the webhook.
+        #[serde(rename = "webhook_code")]
+        code: WebhookCode,
+        /// The item_id of the Item associated with this webhook.
+        item_id: String,
+        /// The number of new investment transactions reported since the last time this webhook was fired.
+        new_investments_transactions: Option<i32>,
+        /// The number of canceled investment transactions reported since the last time this webhook was fired.
+        canceled_investments_transactions: Option<i32>,
+        /// The error that occurred while updating investment transactions, if any.
+        error: Option<ItemError>,
+    },
+    /// An ASSETS webhook.
+    #[serde(rename = "ASSETS")]
+    Assets {
+        /// The webhook_code of the webhook.
+        #[serde(rename = "webhook_code")]
+        code: WebhookCode,
+        /// The asset_report_id that can be provided to /asset_report/get to retrieve the Asset Report.
+        asset_report_id: String,
+        /// The error that occurred while generating the Asset Report, if any.
+        error: Option<ItemError>,
+    },
+    /// A webhook whose webhook_type is not modelled by this crate.
+    #[serde(other)]
+    Other,
+}
+
+impl WebhookEvent {
+    /// Parses the JSON body of a webhook sent by Plaid.
+    ///
+    /// The body should be verified with `Client::verify_webhook` before it is trusted.
+    pub fn from_json(body: &str) -> serde_json::Result<WebhookEvent> {
+        serde_json::from_str(body)
+    }
+}
+
+/// Webhook verification keys fetched by `verify_webhook`, keyed by kid.
+///
+/// Clones of a client share the cache. Clients derived with other settings, e.g. with `Client::with_credentials`, start with an empty one.
+#[derive(Default)]
+pub(crate) struct WebhookKeyCache {
+    keys: Mutex<HashMap<String, (Instant, WebhookVerificationKey)>>,
+}
+
+impl Clone for WebhookKeyCache {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+impl WebhookKeyCache {
+    fn get(&self, kid: &str) -> Option<WebhookVerificationKey> {
+        self.keys
+            .lock()
+            .unwrap()
+            .get(kid)
+            .filter(|(fetched_at, _)| fetched_at.elapsed() < WEBHOOK_KEY_CACHE_TTL)
+            .map(|(_, key)| key.clone())
+    }
+
+    fn insert(&self, key: WebhookVerificationKey) {
+        self.keys
+            .lock()
+            .unwrap()
+            .insert(key.kid.clone(), (Instant::now(), key));
+    }
+}
+
+/// Claims carried by the JWT in the Plaid-Verification header.
+#[derive(Deserialize)]
+struct WebhookClaims {
+    iat: i64,
+    request_body_sha256: String,
+}
+
 impl Client {
     /// Get webhook verification key.
     ///
@@ -54,18 +249,200 @@ impl Client {
         self.send_request(
             "webhook_verification_key/get",
             &GetWebhookVerificationKeyRequest {
-                client_id: &self.client_id,
-                secret: &self.secret,
+                client_id: self.client_id(),
+                secret: self.secret(),
                 key_id,
             },
         )
         .await
     }
+
+    /// Verify a webhook.
+    ///
+    /// Verifies the JWT in the Plaid-Verification header of an incoming webhook against the raw request body. The signing key is fetched using /webhook_verification_key/get and cached by its kid for an hour, as Plaid recommends. The webhook is rejected if the signature is invalid, the key has expired, the token was issued more than 5 minutes ago or more than 30 seconds in the future, or the body does not match the request_body_sha256 claim.
+    ///
+    /// * `body` - The raw body of the webhook request, exactly as received.
+    /// * `plaid_verification_header` - The value of the Plaid-Verification header.
+    pub async fn verify_webhook(&self, body: &str, plaid_verification_header: &str) -> Result<()> {
+        let header = jsonwebtoken::decode_header(plaid_verification_header)
+            .map_err(|err| Error::WebhookVerification(err.to_string()))?;
+        if header.alg != Algorithm::ES256 {
+            return Err(Error::WebhookVerification(format!(
+                "unexpected algorithm {:?}",
+                header.alg
+            )));
+        }
+        let key_id = header
+            .kid
+            .ok_or_else(|| Error::WebhookVerification("missing kid".to_string()))?;
+
+        let key = match self.webhook_keys().get(&key_id) {
+            Some(key) => key,
+            None => {
+                let key = self.get_webhook_verification_key(&key_id).await?.key;
+                if key.expired_at.is_none() {
+                    self.webhook_keys().insert(key.clone());
+                }
+                key
+            }
+        };
+        if key.expired_at.is_some() {
+            return Err(Error::WebhookVerification(format!(
+                "key {} has expired",
+                key_id
+            )));
+        }
+        let decoding_key = DecodingKey::from_ec_components(&key.x, &key.y)
+            .map_err(|err| Error::WebhookVerification(err.to_string()))?;
+
+        let mut validation = Validation::new(Algorithm::ES256);
+        validation.validate_exp = false;
+        validation.required_spec_claims.clear();
+        let claims = jsonwebtoken::decode::<WebhookClaims>(
+            plaid_verification_header,
+            &decoding_key,
+            &validation,
+        )
+        .map_err(|err| Error::WebhookVerification(err.to_string()))?
+        .claims;
+
+        let now = Utc::now().timestamp();
+        if now - claims.iat > WEBHOOK_MAX_AGE_SECS {
+            return Err(Error::WebhookVerification("webhook is too old".to_string()));
+        }
+        if claims.iat - now > WEBHOOK_MAX_CLOCK_SKEW_SECS {
+            return Err(Error::WebhookVerification(
+                "webhook was issued in the future".to_string(),
+            ));
+        }
+
+        let body_sha256: String = Sha256::digest(body.as_bytes())
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect();
+        if body_sha256 != claims.request_body_sha256 {
+            return Err(Error::WebhookVerification(
+                "body does not match request_body_sha256".to_string(),
+            ));
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::client::tests::get_test_client;
+    use super::*;
+    use crate::client::tests::{get_mock_client, get_test_client};
+    use crate::client::Environment;
+    use crate::errors::PlaidErrorCode;
+    use base64::Engine;
+    use jsonwebtoken::{EncodingKey, Header};
+    use ring::rand::SystemRandom;
+    use ring::signature::{EcdsaKeyPair, KeyPair, ECDSA_P256_SHA256_FIXED_SIGNING};
+
+    /// Generates a P-256 signing key, returning it with the /webhook_verification_key/get response that serves its public key.
+    fn generate_key(kid: &str, expired_at: Option<i64>) -> (EncodingKey, serde_json::Value) {
+        let alg = &ECDSA_P256_SHA256_FIXED_SIGNING;
+        let pkcs8 = EcdsaKeyPair::generate_pkcs8(alg, &SystemRandom::new()).unwrap();
+        let key_pair = EcdsaKeyPair::from_pkcs8(alg, pkcs8.as_ref()).unwrap();
+        // The public key is an uncompressed point: 0x04, then the x and y coordinates.
+        let point = key_pair.public_key().as_ref();
+        let encode = |bytes: &[u8]| base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(bytes);
+        let resp = serde_json::json!({
+            "request_id": "RZ6Omi1bzzwDaLo",
+            "key": {
+                "alg": "ES256",
+                "crv": "P-256",
+                "kid": kid,
+                "kty": "EC",
+                "use": "sig",
+                "x": encode(&point[1..33]),
+                "y": encode(&point[33..65]),
+                "created_at": 1560466150,
+                "expired_at": expired_at
+            }
+        });
+        (EncodingKey::from_ec_der(pkcs8.as_ref()), resp)
+    }
+
+    /// Signs a Plaid-Verification header for `body`, issued at `iat`.
+    fn sign(key: &EncodingKey, kid: &str, body: &str, iat: i64) -> String {
+        let mut header = Header::new(Algorithm::ES256);
+        header.kid = Some(kid.to_string());
+        let body_sha256: String = Sha256::digest(body.as_bytes())
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect();
+        jsonwebtoken::encode(
+            &header,
+            &serde_json::json!({"iat": iat, "request_body_sha256": body_sha256}),
+            key,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_webhook_event_from_json() {
+        let event = WebhookEvent::from_json(
+            r#"{
+                "webhook_type": "TRANSACTIONS",
+                "webhook_code": "DEFAULT_UPDATE",
+                "item_id": "wz666MBjYWTp2PDzzggYhM6oWWmBb",
+                "error": null,
+                "new_transactions": 3,
+                "environment": "production"
+            }"#,
+        )
+        .unwrap();
+        match event {
+            WebhookEvent::Transactions {
+                code,
+                item_id,
+                new_transactions,
+                ..
+            } => {
+                assert_eq!(code, WebhookCode::DefaultUpdate);
+                assert_eq!(item_id, "wz666MBjYWTp2PDzzggYhM6oWWmBb");
+                assert_eq!(new_transactions, Some(3));
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+
+        let event = WebhookEvent::from_json(
+            r#"{
+                "webhook_type": "ITEM",
+                "webhook_code": "ERROR",
+                "item_id": "wz666MBjYWTp2PDzzggYhM6oWWmBb",
+                "error": {
+                    "error_type": "ITEM_ERROR",
+                    "error_code": "ITEM_LOGIN_REQUIRED",
+                    "error_message": "the login details of this item have changed",
+                    "display_message": null,
+                    "status": 400
+                },
+                "environment": "production"
+            }"#,
+        )
+        .unwrap();
+        match event {
+            WebhookEvent::Item { code, error, .. } => {
+                assert_eq!(code, WebhookCode::Error);
+                let error = error.unwrap();
+                assert_eq!(error.code(), PlaidErrorCode::ItemLoginRequired);
+                assert_eq!(error.status, Some(400));
+                assert!(error.request_id.is_none());
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+
+        let event = WebhookEvent::from_json(
+            r#"{"webhook_type": "INCOME", "webhook_code": "INCOME_VERIFICATION"}"#,
+        )
+        .unwrap();
+        assert!(matches!(event, WebhookEvent::Other));
+
+        assert!(WebhookEvent::from_json("not json").is_err());
+    }
 
     #[tokio::test]
     async fn test_get_webhook_verification_key() {
@@ -83,4 +460,84 @@ mod tests {
         assert!(!resp.key.y.is_empty());
         assert_ne!(!resp.key.created_at, 0);
     }
+
+    #[tokio::test]
+    async fn test_verify_webhook() {
+        let kid = "6c5516e1-92dc-479e-a8ff-5a51992e0001";
+        let body = r#"{"webhook_type": "ITEM", "webhook_code": "WEBHOOK_UPDATE_ACKNOWLEDGED"}"#;
+        let (key, resp) = generate_key(kid, None);
+        let (client, transport) = get_mock_client();
+        transport.push_json(
+            "webhook_verification_key/get",
+            reqwest::StatusCode::OK,
+            resp,
+        );
+        let now = Utc::now().timestamp();
+
+        client
+            .verify_webhook(body, &sign(&key, kid, body, now))
+            .await
+            .unwrap();
+        let request: serde_json::Value =
+            serde_json::from_slice(&transport.requests()[0].body).unwrap();
+        assert_eq!(request["key_id"], kid);
+
+        let resp = client
+            .verify_webhook("{}", &sign(&key, kid, body, now))
+            .await;
+        assert!(
+            matches!(resp, Err(Error::WebhookVerification(msg)) if msg.contains("request_body_sha256"))
+        );
+
+        let resp = client
+            .verify_webhook(body, &sign(&key, kid, body, now - 10 * 60))
+            .await;
+        assert!(matches!(resp, Err(Error::WebhookVerification(msg)) if msg.contains("too old")));
+
+        let resp = client
+            .verify_webhook(body, &sign(&key, kid, body, now + 10 * 60))
+            .await;
+        assert!(matches!(resp, Err(Error::WebhookVerification(msg)) if msg.contains("future")));
+
+        client
+            .verify_webhook(body, &sign(&key, kid, body, now))
+            .await
+            .unwrap();
+        assert_eq!(transport.requests().len(), 1);
+        client
+            .with_credentials("client_id".to_string(), "secret".to_string())
+            .verify_webhook(body, &sign(&key, kid, body, now))
+            .await
+            .unwrap_err();
+        assert_eq!(transport.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_verify_webhook_expired_key() {
+        let kid = "6c5516e1-92dc-479e-a8ff-5a51992e0001";
+        let body = "{}";
+        let (key, resp) = generate_key(kid, Some(1560466160));
+        let (client, transport) = get_mock_client();
+        transport.push_json(
+            "webhook_verification_key/get",
+            reqwest::StatusCode::OK,
+            resp,
+        );
+
+        let resp = client
+            .verify_webhook(body, &sign(&key, kid, body, Utc::now().timestamp()))
+            .await;
+        assert!(matches!(resp, Err(Error::WebhookVerification(msg)) if msg.contains("expired")));
+    }
+
+    #[tokio::test]
+    async fn test_verify_webhook_malformed_header() {
+        let client = Client::new(
+            "client_id".to_string(),
+            "secret".to_string(),
+            Environment::Sandbox,
+        );
+        let resp = client.verify_webhook("{}", "not-a-jwt").await;
+        assert!(matches!(resp, Err(Error::WebhookVerification(_))));
+    }
 }